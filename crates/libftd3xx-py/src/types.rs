@@ -2,22 +2,25 @@ use libftd3xx_ffi::DWORD;
 use libftd3xx_ffi::UCHAR;
 use libftd3xx_ffi::ULONG;
 use libftd3xx_ffi::USHORT;
+use pyo3::buffer::PyBuffer;
 use pyo3::create_exception;
-use pyo3::exceptions::PyRuntimeError;
+use pyo3::exceptions::{PyIOError, PyRuntimeError, PyValueError};
 use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+use std::collections::HashMap;
 use std::ffi::CStr;
 use std::fmt;
 use std::sync::{Arc, Mutex};
 
 use ::libftd3xx as ftd3xx;
 use ftd3xx::types::Error as FtError;
-use libftd3xx_ffi::{prelude::*, FT_DEVICE_LIST_INFO_NODE, FT_60XCONFIGURATION};
+use libftd3xx_ffi::{prelude::*, FT_DEVICE_LIST_INFO_NODE, FT_60XCONFIGURATION, OVERLAPPED};
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 create_exception!(libftd3xx, FtException, PyRuntimeError);
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub enum Error {
     Generic(FtError),
 }
@@ -25,21 +28,20 @@ impl std::error::Error for Error {}
 
 impl From<Error> for pyo3::PyErr {
     fn from(err: Error) -> Self {
-        let args = match &err {
-            Error::Generic(FtError::APIError(e)) => (err.to_string(), *e as i64),
-            Error::Generic(FtError::CriticalAPIError(_)) => (err.to_string(), -1 as i64),
+        // `FT_STATUS` codes this crate maps to a named variant don't carry
+        // the raw integer any more, so only `Unmapped` has one to surface.
+        let code = match &err {
+            Error::Generic(FtError::Unmapped(status)) => *status as i64,
+            Error::Generic(_) => -1,
         };
-        FtException::new_err(args)
+        FtException::new_err((err.to_string(), code))
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match &self {
-            Self::Generic(FtError::APIError(e)) => write!(f, "API Error occurred: {e:?}"),
-            Self::Generic(FtError::CriticalAPIError(s)) => {
-                write!(f, "Critical API Error occurred: {s}")
-            }
+            Self::Generic(e) => write!(f, "{e}"),
         }
     }
 }
@@ -205,6 +207,129 @@ impl FtDeviceListInfoNode {
     }
 }
 
+/// FT60x FIFO interface mode: the raw `FIFOMode` byte of
+/// `FT_60XCONFIGURATION`, typed to reject out-of-range values.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FifoMode {
+    /// 245 FIFO mode: a single synchronous/asynchronous FIFO interface.
+    Mode245,
+    /// 600 FIFO mode: FTDI's multi-channel FIFO interface.
+    Mode600,
+}
+
+impl TryFrom<UCHAR> for FifoMode {
+    type Error = Error;
+
+    fn try_from(value: UCHAR) -> Result<Self> {
+        match value {
+            0 => Ok(FifoMode::Mode245),
+            1 => Ok(FifoMode::Mode600),
+            _ => Err(Error::from(FtError::from(
+                format!("unrecognized FIFOMode byte: {value}").as_str(),
+            ))),
+        }
+    }
+}
+
+impl From<FifoMode> for UCHAR {
+    fn from(value: FifoMode) -> Self {
+        match value {
+            FifoMode::Mode245 => 0,
+            FifoMode::Mode600 => 1,
+        }
+    }
+}
+
+/// FT60x FIFO clock rate: the raw `FIFOClock` byte of `FT_60XCONFIGURATION`,
+/// typed to reject out-of-range values.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FifoClock {
+    /// 100 MHz.
+    Clk100MHz,
+    /// 66 MHz.
+    Clk66MHz,
+    /// 50 MHz.
+    Clk50MHz,
+    /// 40 MHz.
+    Clk40MHz,
+}
+
+impl TryFrom<UCHAR> for FifoClock {
+    type Error = Error;
+
+    fn try_from(value: UCHAR) -> Result<Self> {
+        match value {
+            0 => Ok(FifoClock::Clk100MHz),
+            1 => Ok(FifoClock::Clk66MHz),
+            2 => Ok(FifoClock::Clk50MHz),
+            3 => Ok(FifoClock::Clk40MHz),
+            _ => Err(Error::from(FtError::from(
+                format!("unrecognized FIFOClock byte: {value}").as_str(),
+            ))),
+        }
+    }
+}
+
+impl From<FifoClock> for UCHAR {
+    fn from(value: FifoClock) -> Self {
+        match value {
+            FifoClock::Clk100MHz => 0,
+            FifoClock::Clk66MHz => 1,
+            FifoClock::Clk50MHz => 2,
+            FifoClock::Clk40MHz => 3,
+        }
+    }
+}
+
+/// The number of FIFO channels, and which directions they support: the raw
+/// `ChannelConfig` byte of `FT_60XCONFIGURATION`, typed to reject
+/// out-of-range values.
+#[pyclass]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ChannelConfig {
+    /// 4 bidirectional channels.
+    Four,
+    /// 2 bidirectional channels.
+    Two,
+    /// 1 bidirectional channel.
+    One,
+    /// 1 channel, OUT (host-to-device) only.
+    OneOutPipe,
+    /// 1 channel, IN (device-to-host) only.
+    OneInPipe,
+}
+
+impl TryFrom<UCHAR> for ChannelConfig {
+    type Error = Error;
+
+    fn try_from(value: UCHAR) -> Result<Self> {
+        match value {
+            0 => Ok(ChannelConfig::Four),
+            1 => Ok(ChannelConfig::Two),
+            2 => Ok(ChannelConfig::One),
+            3 => Ok(ChannelConfig::OneOutPipe),
+            4 => Ok(ChannelConfig::OneInPipe),
+            _ => Err(Error::from(FtError::from(
+                format!("unrecognized ChannelConfig byte: {value}").as_str(),
+            ))),
+        }
+    }
+}
+
+impl From<ChannelConfig> for UCHAR {
+    fn from(value: ChannelConfig) -> Self {
+        match value {
+            ChannelConfig::Four => 0,
+            ChannelConfig::Two => 1,
+            ChannelConfig::One => 2,
+            ChannelConfig::OneOutPipe => 3,
+            ChannelConfig::OneInPipe => 4,
+        }
+    }
+}
+
 define_basic_py_object_no_new!(Ft60xConfiguration, FT_60XCONFIGURATION);
 
 impl Ft60xConfiguration {
@@ -235,8 +360,7 @@ impl Ft60xConfiguration {
     }
     #[setter(VendorID)]
     fn set_vendor_id(&mut self, value: USHORT) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.VendorID = value;
+        self.0.lock().unwrap().VendorID = value;
         Ok(())
     }
 
@@ -247,8 +371,7 @@ impl Ft60xConfiguration {
     }
     #[setter(ProductID)]
     fn set_product_id(&mut self, value: USHORT) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.ProductID = value;
+        self.0.lock().unwrap().ProductID = value;
         Ok(())
     }
 
@@ -260,8 +383,7 @@ impl Ft60xConfiguration {
     }
     #[setter(StringDescriptors)]
     fn set_string_descriptors(&mut self, value: [UCHAR; 128usize]) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.StringDescriptors = value;
+        self.0.lock().unwrap().StringDescriptors = value;
         Ok(())
     }
 
@@ -272,8 +394,7 @@ impl Ft60xConfiguration {
     }
     #[setter(bInterval)]
     fn set_b_interval(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.bInterval = value;
+        self.0.lock().unwrap().bInterval = value;
         Ok(())
     }
 
@@ -284,8 +405,7 @@ impl Ft60xConfiguration {
     }
     #[setter(PowerAttributes)]
     fn set_power_attributes(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.PowerAttributes = value;
+        self.0.lock().unwrap().PowerAttributes = value;
         Ok(())
     }
 
@@ -296,8 +416,7 @@ impl Ft60xConfiguration {
     }
     #[setter(PowerConsumption)]
     fn set_power_consumption(&mut self, value: USHORT) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.PowerConsumption = value;
+        self.0.lock().unwrap().PowerConsumption = value;
         Ok(())
     }
 
@@ -308,44 +427,40 @@ impl Ft60xConfiguration {
     }
     #[setter(Reserved2)]
     fn set_reserved2(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.Reserved2 = value;
+        self.0.lock().unwrap().Reserved2 = value;
         Ok(())
     }
 
     #[getter(FIFOClock)]
-    fn get_fifo_clock(&self) -> PyResult<UCHAR> {
+    fn get_fifo_clock(&self) -> PyResult<FifoClock> {
         let config = *self.0.lock().unwrap();
-        Ok(config.FIFOClock)
+        Ok(FifoClock::try_from(config.FIFOClock)?)
     }
     #[setter(FIFOClock)]
-    fn set_fifo_clock(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.FIFOClock = value;
+    fn set_fifo_clock(&mut self, value: FifoClock) -> PyResult<()> {
+        self.0.lock().unwrap().FIFOClock = UCHAR::from(value);
         Ok(())
     }
 
     #[getter(FIFOMode)]
-    fn get_fifo_mode(&self) -> PyResult<UCHAR> {
+    fn get_fifo_mode(&self) -> PyResult<FifoMode> {
         let config = *self.0.lock().unwrap();
-        Ok(config.FIFOMode)
+        Ok(FifoMode::try_from(config.FIFOMode)?)
     }
     #[setter(FIFOMode)]
-    fn set_fifo_mode(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.FIFOMode = value;
+    fn set_fifo_mode(&mut self, value: FifoMode) -> PyResult<()> {
+        self.0.lock().unwrap().FIFOMode = UCHAR::from(value);
         Ok(())
     }
 
     #[getter(ChannelConfig)]
-    fn get_channel_config(&self) -> PyResult<UCHAR> {
+    fn get_channel_config(&self) -> PyResult<ChannelConfig> {
         let config = *self.0.lock().unwrap();
-        Ok(config.ChannelConfig)
+        Ok(ChannelConfig::try_from(config.ChannelConfig)?)
     }
     #[setter(ChannelConfig)]
-    fn set_channel_config(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.ChannelConfig = value;
+    fn set_channel_config(&mut self, value: ChannelConfig) -> PyResult<()> {
+        self.0.lock().unwrap().ChannelConfig = UCHAR::from(value);
         Ok(())
     }
 
@@ -356,8 +471,7 @@ impl Ft60xConfiguration {
     }
     #[setter(OptionalFeatureSupport)]
     fn set_optional_feature_support(&mut self, value: USHORT) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.OptionalFeatureSupport = value;
+        self.0.lock().unwrap().OptionalFeatureSupport = value;
         Ok(())
     }
 
@@ -368,8 +482,7 @@ impl Ft60xConfiguration {
     }
     #[setter(BatteryChargingGPIOConfig)]
     fn set_battery_charging_gpio_config(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.BatteryChargingGPIOConfig = value;
+        self.0.lock().unwrap().BatteryChargingGPIOConfig = value;
         Ok(())
     }
 
@@ -380,8 +493,7 @@ impl Ft60xConfiguration {
     }
     #[setter(FlashEEPROMDetection)]
     fn set_flash_eeprom_detection(&mut self, value: UCHAR) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.FlashEEPROMDetection = value;
+        self.0.lock().unwrap().FlashEEPROMDetection = value;
         Ok(())
     }
 
@@ -392,8 +504,7 @@ impl Ft60xConfiguration {
     }
     #[setter(MSIO_Control)]
     fn set_msio_control(&mut self, value: ULONG) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.MSIO_Control = value;
+        self.0.lock().unwrap().MSIO_Control = value;
         Ok(())
     }
 
@@ -404,8 +515,520 @@ impl Ft60xConfiguration {
     }
     #[setter(GPIO_Control)]
     fn set_gpio_control(&mut self, value: ULONG) -> PyResult<()> {
-        let mut config = *self.0.lock().unwrap();
-        config.GPIO_Control = value;
+        self.0.lock().unwrap().GPIO_Control = value;
         Ok(())
-    }    
+    }
+
+    /// Returns every field of this configuration as a `dict`, keyed by the
+    /// `FT_60XCONFIGURATION` field name.
+    fn to_dict<'py>(&self, py: Python<'py>) -> PyResult<&'py PyDict> {
+        let config = *self.0.lock().unwrap();
+        let dict = PyDict::new(py);
+        dict.set_item("VendorID", config.VendorID)?;
+        dict.set_item("ProductID", config.ProductID)?;
+        dict.set_item("StringDescriptors", PyBytes::new(py, &config.StringDescriptors))?;
+        dict.set_item("bInterval", config.bInterval)?;
+        dict.set_item("PowerAttributes", config.PowerAttributes)?;
+        dict.set_item("PowerConsumption", config.PowerConsumption)?;
+        dict.set_item("Reserved2", config.Reserved2)?;
+        dict.set_item("FIFOClock", config.FIFOClock)?;
+        dict.set_item("FIFOMode", config.FIFOMode)?;
+        dict.set_item("ChannelConfig", config.ChannelConfig)?;
+        dict.set_item("OptionalFeatureSupport", config.OptionalFeatureSupport)?;
+        dict.set_item(
+            "BatteryChargingGPIOConfig",
+            config.BatteryChargingGPIOConfig,
+        )?;
+        dict.set_item("FlashEEPROMDetection", config.FlashEEPROMDetection)?;
+        dict.set_item("MSIO_Control", config.MSIO_Control)?;
+        dict.set_item("GPIO_Control", config.GPIO_Control)?;
+        Ok(dict)
+    }
+
+    /// Builds a configuration from a `dict` produced by
+    /// [`Ft60xConfiguration::to_dict`] (or containing the same keys).
+    #[staticmethod]
+    fn from_dict(dict: &PyDict) -> PyResult<Self> {
+        let string_descriptors: Vec<u8> = get_dict_item(dict, "StringDescriptors")?.extract()?;
+        let string_descriptors: [UCHAR; 128] =
+            string_descriptors.try_into().map_err(|v: Vec<u8>| {
+                PyValueError::new_err(format!(
+                    "StringDescriptors must be 128 bytes, got {}",
+                    v.len()
+                ))
+            })?;
+
+        Ok(Self::from(FT_60XCONFIGURATION {
+            VendorID: get_dict_item(dict, "VendorID")?.extract()?,
+            ProductID: get_dict_item(dict, "ProductID")?.extract()?,
+            StringDescriptors: string_descriptors,
+            bInterval: get_dict_item(dict, "bInterval")?.extract()?,
+            PowerAttributes: get_dict_item(dict, "PowerAttributes")?.extract()?,
+            PowerConsumption: get_dict_item(dict, "PowerConsumption")?.extract()?,
+            Reserved2: get_dict_item(dict, "Reserved2")?.extract()?,
+            FIFOClock: get_dict_item(dict, "FIFOClock")?.extract()?,
+            FIFOMode: get_dict_item(dict, "FIFOMode")?.extract()?,
+            ChannelConfig: get_dict_item(dict, "ChannelConfig")?.extract()?,
+            OptionalFeatureSupport: get_dict_item(dict, "OptionalFeatureSupport")?.extract()?,
+            BatteryChargingGPIOConfig: get_dict_item(dict, "BatteryChargingGPIOConfig")?
+                .extract()?,
+            FlashEEPROMDetection: get_dict_item(dict, "FlashEEPROMDetection")?.extract()?,
+            MSIO_Control: get_dict_item(dict, "MSIO_Control")?.extract()?,
+            GPIO_Control: get_dict_item(dict, "GPIO_Control")?.extract()?,
+        }))
+    }
+
+    /// Writes every field of this configuration to `path` as one
+    /// `key=value` line per field, so it can be version-controlled, edited,
+    /// read back with [`Ft60xConfiguration::load`], and re-flashed with
+    /// [`Device::program_from_file`].
+    fn save(&self, path: String) -> PyResult<()> {
+        let config = *self.0.lock().unwrap();
+        let mut text = String::new();
+        text.push_str(&format!("VendorID={}\n", config.VendorID));
+        text.push_str(&format!("ProductID={}\n", config.ProductID));
+        text.push_str(&format!(
+            "StringDescriptors={}\n",
+            hex_encode(&config.StringDescriptors)
+        ));
+        text.push_str(&format!("bInterval={}\n", config.bInterval));
+        text.push_str(&format!("PowerAttributes={}\n", config.PowerAttributes));
+        text.push_str(&format!("PowerConsumption={}\n", config.PowerConsumption));
+        text.push_str(&format!("Reserved2={}\n", config.Reserved2));
+        text.push_str(&format!("FIFOClock={}\n", config.FIFOClock));
+        text.push_str(&format!("FIFOMode={}\n", config.FIFOMode));
+        text.push_str(&format!("ChannelConfig={}\n", config.ChannelConfig));
+        text.push_str(&format!(
+            "OptionalFeatureSupport={}\n",
+            config.OptionalFeatureSupport
+        ));
+        text.push_str(&format!(
+            "BatteryChargingGPIOConfig={}\n",
+            config.BatteryChargingGPIOConfig
+        ));
+        text.push_str(&format!(
+            "FlashEEPROMDetection={}\n",
+            config.FlashEEPROMDetection
+        ));
+        text.push_str(&format!("MSIO_Control={}\n", config.MSIO_Control));
+        text.push_str(&format!("GPIO_Control={}\n", config.GPIO_Control));
+        std::fs::write(&path, text).map_err(|e| PyIOError::new_err(e.to_string()))
+    }
+
+    /// Reads a configuration previously written by [`Ft60xConfiguration::save`].
+    #[staticmethod]
+    fn load(path: String) -> PyResult<Self> {
+        let text = std::fs::read_to_string(&path).map_err(|e| PyIOError::new_err(e.to_string()))?;
+        let mut fields = HashMap::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let (key, value) = line.split_once('=').ok_or_else(|| {
+                PyValueError::new_err(format!("malformed config line: {line}"))
+            })?;
+            fields.insert(key.trim().to_string(), value.trim().to_string());
+        }
+
+        let string_descriptors = hex_decode(get_field(&fields, "StringDescriptors")?)?;
+        let string_descriptors: [UCHAR; 128] =
+            string_descriptors.try_into().map_err(|v: Vec<u8>| {
+                PyValueError::new_err(format!(
+                    "StringDescriptors must decode to 128 bytes, got {}",
+                    v.len()
+                ))
+            })?;
+
+        Ok(Self::from(FT_60XCONFIGURATION {
+            VendorID: parse_field(&fields, "VendorID")?,
+            ProductID: parse_field(&fields, "ProductID")?,
+            StringDescriptors: string_descriptors,
+            bInterval: parse_field(&fields, "bInterval")?,
+            PowerAttributes: parse_field(&fields, "PowerAttributes")?,
+            PowerConsumption: parse_field(&fields, "PowerConsumption")?,
+            Reserved2: parse_field(&fields, "Reserved2")?,
+            FIFOClock: parse_field(&fields, "FIFOClock")?,
+            FIFOMode: parse_field(&fields, "FIFOMode")?,
+            ChannelConfig: parse_field(&fields, "ChannelConfig")?,
+            OptionalFeatureSupport: parse_field(&fields, "OptionalFeatureSupport")?,
+            BatteryChargingGPIOConfig: parse_field(&fields, "BatteryChargingGPIOConfig")?,
+            FlashEEPROMDetection: parse_field(&fields, "FlashEEPROMDetection")?,
+            MSIO_Control: parse_field(&fields, "MSIO_Control")?,
+            GPIO_Control: parse_field(&fields, "GPIO_Control")?,
+        }))
+    }
+}
+
+fn get_dict_item<'py>(dict: &'py PyDict, key: &str) -> PyResult<&'py PyAny> {
+    dict.get_item(key)?
+        .ok_or_else(|| PyValueError::new_err(format!("missing config key: {key}")))
+}
+
+fn get_field<'a>(fields: &'a HashMap<String, String>, key: &str) -> PyResult<&'a str> {
+    fields
+        .get(key)
+        .map(String::as_str)
+        .ok_or_else(|| PyValueError::new_err(format!("missing config key: {key}")))
+}
+
+fn parse_field<T>(fields: &HashMap<String, String>, key: &str) -> PyResult<T>
+where
+    T: std::str::FromStr,
+    T::Err: fmt::Display,
+{
+    get_field(fields, key)?
+        .parse()
+        .map_err(|e| PyValueError::new_err(format!("invalid value for {key}: {e}")))
+}
+
+fn hex_encode(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+fn hex_decode(s: &str) -> PyResult<Vec<u8>> {
+    if s.len() % 2 != 0 {
+        return Err(PyValueError::new_err("hex string must have even length"));
+    }
+    (0..s.len())
+        .step_by(2)
+        .map(|i| {
+            u8::from_str_radix(&s[i..i + 2], 16)
+                .map_err(|e| PyValueError::new_err(format!("invalid hex byte: {e}")))
+        })
+        .collect()
+}
+
+/// Creates an `asyncio.Future` bound to the running event loop, for a
+/// background thread to later resolve with [`resolve_future`].
+fn new_future(py: Python<'_>) -> PyResult<(Py<PyAny>, Py<PyAny>)> {
+    let asyncio = py.import("asyncio")?;
+    let event_loop = asyncio.call_method0("get_event_loop")?;
+    let future = event_loop.call_method0("create_future")?;
+    Ok((event_loop.into(), future.into()))
+}
+
+/// Delivers the outcome of a transfer submitted on a background thread to
+/// `future`, waking `event_loop` via `call_soon_threadsafe` so the awaiting
+/// coroutine resumes on the event loop's own thread.
+fn resolve_future(
+    py: Python<'_>,
+    event_loop: &Py<PyAny>,
+    future: &Py<PyAny>,
+    outcome: PyResult<Py<PyAny>>,
+) {
+    let set = match outcome {
+        Ok(value) => future.as_ref(py).getattr("set_result").map(|m| (m, value)),
+        Err(err) => future
+            .as_ref(py)
+            .getattr("set_exception")
+            .map(|m| (m, err.into_py(py))),
+    };
+    if let Ok((method, value)) = set {
+        let _ = event_loop
+            .as_ref(py)
+            .call_method1("call_soon_threadsafe", (method, value));
+    }
+}
+
+define_basic_py_object_no_new!(FtOverlapped, OVERLAPPED);
+
+impl FtOverlapped {
+    fn new() -> Self {
+        Self {
+            0: Arc::new(Mutex::new(OVERLAPPED::default())),
+        }
+    }
+}
+
+#[pymethods]
+impl FtOverlapped {
+    #[new]
+    fn py_new() -> Self {
+        Self::new()
+    }
+}
+
+/// An open D3XX device.
+///
+/// Obtain one with [`Device::open_by_index`]/[`Device::open_by_serial_number`]/
+/// [`Device::open_by_description`] rather than constructing it directly.
+/// Supports the `with` statement: the handle is closed on `__exit__`.
+#[pyclass]
+#[derive(Debug)]
+pub struct Device(pub Arc<Mutex<FT_HANDLE>>);
+
+// Arc is only Send if T is Send so lets mark it as safe here
+unsafe impl Send for Device {}
+
+impl Device {
+    fn from_handle(handle: FT_HANDLE) -> Self {
+        Self(Arc::new(Mutex::new(handle)))
+    }
+}
+
+#[pymethods]
+impl Device {
+    /// Opens the device at `index` in the list returned by `list()`.
+    #[staticmethod]
+    fn open_by_index(index: u32) -> Result<Self> {
+        Ok(Self::from_handle(ftd3xx::functions::create_by_index(
+            index,
+        )?))
+    }
+
+    /// Opens the device with the given USB serial number string descriptor.
+    #[staticmethod]
+    fn open_by_serial_number(serial_number: String) -> Result<Self> {
+        Ok(Self::from_handle(
+            ftd3xx::functions::create_by_serial_number(serial_number)?,
+        ))
+    }
+
+    /// Opens the device with the given USB product description string
+    /// descriptor.
+    #[staticmethod]
+    fn open_by_description(description: String) -> Result<Self> {
+        Ok(Self::from_handle(ftd3xx::functions::create_by_description(
+            description,
+        )?))
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &self,
+        _exc_type: &PyAny,
+        _exc_value: &PyAny,
+        _traceback: &PyAny,
+    ) -> Result<()> {
+        self.close()
+    }
+
+    /// Closes the device handle. A no-op if already closed.
+    ///
+    /// See [`ftd3xx::functions::close`].
+    fn close(&self) -> Result<()> {
+        let mut handle = self.0.lock().unwrap();
+        if handle.is_null() {
+            return Ok(());
+        }
+        ftd3xx::functions::close(*handle)?;
+        *handle = std::ptr::null_mut();
+        Ok(())
+    }
+
+    /// See [`ftd3xx::functions::get_driver_version`].
+    fn driver_version(&self) -> Result<Version> {
+        Ok(Version {
+            0: ftd3xx::functions::get_driver_version(*self.0.lock().unwrap())?,
+        })
+    }
+
+    /// See [`ftd3xx::functions::get_chip_configuration`].
+    fn get_chip_configuration(&self) -> Result<Ft60xConfiguration> {
+        Ok(Ft60xConfiguration::from(
+            ftd3xx::functions::get_chip_configuration(*self.0.lock().unwrap())?,
+        ))
+    }
+
+    /// See [`ftd3xx::functions::set_chip_configuration`].
+    fn set_chip_configuration(&self, config: Option<&Ft60xConfiguration>) -> Result<()> {
+        Ok(match config {
+            Some(c) => ftd3xx::functions::set_chip_configuration(
+                *self.0.lock().unwrap(),
+                Some(*c.0.lock().unwrap()),
+            )?,
+            None => ftd3xx::functions::set_chip_configuration(*self.0.lock().unwrap(), None)?,
+        })
+    }
+
+    /// Writes the bytes of `buffer` (e.g. a `bytes`/`bytearray`/`memoryview`)
+    /// to `pipe_id`, returning the number of bytes actually written.
+    ///
+    /// The transfer itself runs with the GIL released, so other Python
+    /// threads keep running while this call blocks on the USB transfer.
+    ///
+    /// See [`ftd3xx::functions::write_pipe`].
+    fn write_pipe(&self, py: Python<'_>, pipe_id: u8, buffer: PyBuffer<u8>) -> PyResult<u32> {
+        let mut data = buffer.to_vec(py)?;
+        let handle = *self.0.lock().unwrap();
+        let written = py
+            .allow_threads(|| ftd3xx::functions::write_pipe(handle, pipe_id, &mut data, std::ptr::null_mut()))
+            .map_err(Error::from)?;
+        Ok(written)
+    }
+
+    /// Reads from `pipe_id` into `buffer` (e.g. a preallocated `bytearray`/
+    /// `memoryview`), returning the number of bytes actually read.
+    ///
+    /// The transfer itself runs with the GIL released, so other Python
+    /// threads keep running while this call blocks on the USB transfer.
+    ///
+    /// See [`ftd3xx::functions::read_pipe`].
+    fn read_pipe(&self, py: Python<'_>, pipe_id: u8, buffer: PyBuffer<u8>) -> PyResult<u32> {
+        let mut data = vec![0u8; buffer.len_bytes()];
+        let handle = *self.0.lock().unwrap();
+        let read = py
+            .allow_threads(|| ftd3xx::functions::read_pipe(handle, pipe_id, &mut data, std::ptr::null_mut()))
+            .map_err(Error::from)?;
+        buffer.copy_from_slice(py, &data)?;
+        Ok(read)
+    }
+
+    /// Like [`Device::read_pipe`], but returns an `asyncio`-awaitable
+    /// resolving to a `bytes` object of up to `length` bytes, instead of
+    /// blocking the calling thread.
+    ///
+    /// The transfer is submitted on a background thread (so the GIL is
+    /// never held for the duration of the USB transfer); its completion is
+    /// delivered back to the event loop via
+    /// [`asyncio.loop.call_soon_threadsafe`][py-call-soon-threadsafe],
+    /// the same mechanism `loop.run_in_executor` uses under the hood.
+    ///
+    /// [py-call-soon-threadsafe]: https://docs.python.org/3/library/asyncio-eventloop.html#asyncio.loop.call_soon_threadsafe
+    fn read_pipe_async<'py>(&self, py: Python<'py>, pipe_id: u8, length: usize) -> PyResult<&'py PyAny> {
+        let (event_loop, future) = new_future(py)?;
+        let handle = Arc::clone(&self.0);
+
+        std::thread::spawn(move || {
+            let mut data = vec![0u8; length];
+            let handle = *handle.lock().unwrap();
+            let result = ftd3xx::functions::read_pipe(handle, pipe_id, &mut data, std::ptr::null_mut());
+            Python::with_gil(|py| {
+                let outcome = result
+                    .map(|n| {
+                        data.truncate(n as usize);
+                        PyBytes::new(py, &data).into_py(py)
+                    })
+                    .map_err(|e| PyErr::from(Error::from(e)));
+                resolve_future(py, &event_loop, &future, outcome);
+            });
+        });
+
+        Ok(future.into_ref(py))
+    }
+
+    /// Like [`Device::write_pipe`], but returns an `asyncio`-awaitable
+    /// resolving to the number of bytes written, instead of blocking the
+    /// calling thread. See [`Device::read_pipe_async`] for how completion is
+    /// delivered to the event loop.
+    fn write_pipe_async<'py>(
+        &self,
+        py: Python<'py>,
+        pipe_id: u8,
+        buffer: PyBuffer<u8>,
+    ) -> PyResult<&'py PyAny> {
+        let mut data = buffer.to_vec(py)?;
+        let (event_loop, future) = new_future(py)?;
+        let handle = Arc::clone(&self.0);
+
+        std::thread::spawn(move || {
+            let handle = *handle.lock().unwrap();
+            let result =
+                ftd3xx::functions::write_pipe(handle, pipe_id, &mut data, std::ptr::null_mut());
+            Python::with_gil(|py| {
+                let outcome = result
+                    .map(|n| n.into_py(py))
+                    .map_err(|e| PyErr::from(Error::from(e)));
+                resolve_future(py, &event_loop, &future, outcome);
+            });
+        });
+
+        Ok(future.into_ref(py))
+    }
+
+    /// Retrieves the result of an asynchronous (overlapped) pipe transfer
+    /// previously submitted against `overlapped`.
+    ///
+    /// See [`ftd3xx::functions::get_overlapped_result`].
+    fn get_overlapped_result(&self, overlapped: &FtOverlapped, wait: bool) -> Result<u32> {
+        Ok(ftd3xx::functions::get_overlapped_result(
+            *self.0.lock().unwrap(),
+            &mut *overlapped.0.lock().unwrap() as *mut _,
+            wait,
+        )?)
+    }
+
+    /// Loads a chip configuration previously saved with
+    /// [`Ft60xConfiguration::save`] and applies it to this device.
+    ///
+    /// See [`Ft60xConfiguration::load`]/[`Device::set_chip_configuration`].
+    fn program_from_file(&self, path: String) -> PyResult<()> {
+        let config = Ft60xConfiguration::load(path)?;
+        self.set_chip_configuration(Some(&config))
+            .map_err(PyErr::from)
+    }
+}
+
+/// A pipe reader whose transfer buffer and stream-pipe setup are prepared
+/// once at construction rather than on every transfer.
+///
+/// For repeated high-throughput reads (logic-capture / continuous
+/// acquisition), allocating a fresh buffer and re-running `FT_SetStreamPipe`
+/// on every call dominates overhead; `StreamTransfer` performs that setup a
+/// single time and every [`StreamTransfer::next`] call reuses it, only
+/// blocking on the transfer's completion.
+#[pyclass]
+pub struct StreamTransfer {
+    handle: Arc<Mutex<FT_HANDLE>>,
+    pipe_id: u8,
+    buffer: Vec<u8>,
+}
+
+// Arc is only Send if T is Send so lets mark it as safe here
+unsafe impl Send for StreamTransfer {}
+
+#[pymethods]
+impl StreamTransfer {
+    /// Enables streaming on `pipe_id` and preallocates a `buffer_size`-byte
+    /// transfer buffer, both reused by every subsequent `next()` call.
+    ///
+    /// See [`ftd3xx::functions::set_stream_pipe`].
+    #[new]
+    fn py_new(device: &Device, pipe_id: u8, buffer_size: usize) -> Result<Self> {
+        let handle = *device.0.lock().unwrap();
+        ftd3xx::functions::set_stream_pipe(handle, false, false, pipe_id, buffer_size as ULONG)?;
+        Ok(Self {
+            handle: Arc::clone(&device.0),
+            pipe_id,
+            buffer: vec![0u8; buffer_size],
+        })
+    }
+
+    /// Blocks for the next transfer, returning the bytes actually read.
+    ///
+    /// See [`ftd3xx::functions::read_pipe`].
+    fn next(&mut self, py: Python<'_>) -> PyResult<Py<PyBytes>> {
+        let handle = *self.handle.lock().unwrap();
+        let pipe_id = self.pipe_id;
+        let buffer = &mut self.buffer;
+        let read = py
+            .allow_threads(|| ftd3xx::functions::read_pipe(handle, pipe_id, buffer, std::ptr::null_mut()))
+            .map_err(Error::from)?;
+        Ok(PyBytes::new(py, &self.buffer[..read as usize]).into())
+    }
+
+    /// Disables the streaming set up at construction. Every call issues
+    /// `FT_ClearStreamPipe` again; repeated calls are not skipped.
+    ///
+    /// See [`ftd3xx::functions::clear_stream_pipe`].
+    fn close(&self) -> Result<()> {
+        Ok(ftd3xx::functions::clear_stream_pipe(
+            *self.handle.lock().unwrap(),
+            false,
+            false,
+            self.pipe_id,
+        )?)
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(&self, _exc_type: &PyAny, _exc_value: &PyAny, _traceback: &PyAny) -> Result<()> {
+        self.close()
+    }
 }
@@ -0,0 +1,144 @@
+#![deny(missing_docs)]
+///! Runtime (`dlopen`/`LoadLibrary`) loading of the D3XX driver.
+///!
+///! Enabling the `static` cargo feature makes this crate expect
+///! `libftd3xx_ffi` to have linked the D3XX driver (`libftd3xx.so` /
+///! `FTD3XX.dll`) at build time, and every function in
+///! [`crate::functions`] then calls straight into `libftd3xx_ffi`.
+///!
+///! With `static` disabled (the default), no `FT_*` symbols are linked at
+///! build time. An application must instead call [`load_dylib`] once, before
+///! using anything in [`crate::functions`], to resolve the driver's symbols
+///! into a table at runtime. Every safe function then dispatches through
+///! that table, returning [`crate::types::Error::LibraryNotLoaded`] if
+///! nothing has been loaded yet. This mirrors the loader used by the
+///! `ft60x_rs` crate for the sibling FT60x driver.
+///!
+///! This crate does not bundle a copy of the driver itself (there is no
+///! redistributable binary to extract); callers are responsible for
+///! installing the D3XX driver and pointing [`load_dylib`] at it.
+use std::ffi::c_void;
+use std::path::Path;
+use std::sync::OnceLock;
+
+use libloading::Library;
+
+use crate::types::{Error, Result};
+use libftd3xx_ffi::{FT_DEVICE_LIST_INFO_NODE, FT_HANDLE, LPOVERLAPPED, PULONG, UCHAR, ULONG};
+
+/// The raw status code returned by every `FT_*` entry point, before it is
+/// converted to an [`libftd3xx_ffi::prelude::FT_Status`].
+pub(crate) type RawStatus = i32;
+
+macro_rules! symbol_table {
+    ($($field:ident : unsafe extern "C" fn($($arg:ty),* $(,)?) -> RawStatus),* $(,)?) => {
+        /// Resolved `FT_*` entry points from a loaded D3XX driver library.
+        #[allow(non_snake_case)]
+        pub(crate) struct Symbols {
+            $(pub(crate) $field: unsafe extern "C" fn($($arg),*) -> RawStatus,)*
+        }
+
+        impl Symbols {
+            /// # Safety
+            ///
+            /// `lib` must stay loaded for as long as any `Symbols` resolved
+            /// from it is in use.
+            unsafe fn resolve(lib: &Library) -> Result<Symbols> {
+                Ok(Symbols {
+                    $($field: {
+                        let symbol: libloading::Symbol<
+                            unsafe extern "C" fn($($arg),*) -> RawStatus,
+                        > = lib.get(stringify!($field).as_bytes())?;
+                        *symbol.into_raw()
+                    },)*
+                })
+            }
+        }
+    };
+}
+
+symbol_table! {
+    FT_GetLibraryVersion: unsafe extern "C" fn(*mut ULONG) -> RawStatus,
+    FT_GetDriverVersion: unsafe extern "C" fn(FT_HANDLE, *mut ULONG) -> RawStatus,
+    FT_CreateDeviceInfoList: unsafe extern "C" fn(*mut ULONG) -> RawStatus,
+    FT_GetDeviceInfoList: unsafe extern "C" fn(*mut FT_DEVICE_LIST_INFO_NODE, *mut ULONG) -> RawStatus,
+    FT_GetDeviceInfoDetail: unsafe extern "C" fn(ULONG, *mut ULONG, *mut ULONG, *mut ULONG, *mut ULONG, *mut c_void, *mut c_void, *mut FT_HANDLE) -> RawStatus,
+    FT_Create: unsafe extern "C" fn(*mut c_void, ULONG, *mut FT_HANDLE) -> RawStatus,
+    FT_Close: unsafe extern "C" fn(FT_HANDLE) -> RawStatus,
+    FT_WritePipe: unsafe extern "C" fn(FT_HANDLE, UCHAR, *mut u8, ULONG, PULONG, LPOVERLAPPED) -> RawStatus,
+    FT_ReadPipe: unsafe extern "C" fn(FT_HANDLE, UCHAR, *mut u8, ULONG, PULONG, LPOVERLAPPED) -> RawStatus,
+    FT_WritePipeEx: unsafe extern "C" fn(FT_HANDLE, UCHAR, *mut u8, ULONG, PULONG, LPOVERLAPPED) -> RawStatus,
+    FT_ReadPipeEx: unsafe extern "C" fn(FT_HANDLE, UCHAR, *mut u8, ULONG, PULONG, LPOVERLAPPED) -> RawStatus,
+    FT_GetOverlappedResult: unsafe extern "C" fn(FT_HANDLE, LPOVERLAPPED, PULONG, ULONG) -> RawStatus,
+    FT_InitializeOverlapped: unsafe extern "C" fn(FT_HANDLE, LPOVERLAPPED) -> RawStatus,
+    FT_ReleaseOverlapped: unsafe extern "C" fn(FT_HANDLE, LPOVERLAPPED) -> RawStatus,
+    FT_SetStreamPipe: unsafe extern "C" fn(FT_HANDLE, ULONG, ULONG, ULONG, ULONG) -> RawStatus,
+    FT_ClearStreamPipe: unsafe extern "C" fn(FT_HANDLE, ULONG, ULONG, ULONG) -> RawStatus,
+    FT_SetPipeTimeout: unsafe extern "C" fn(FT_HANDLE, ULONG, ULONG) -> RawStatus,
+    FT_AbortPipe: unsafe extern "C" fn(FT_HANDLE, ULONG) -> RawStatus,
+    FT_GetChipConfiguration: unsafe extern "C" fn(FT_HANDLE, *mut c_void) -> RawStatus,
+    FT_SetChipConfiguration: unsafe extern "C" fn(FT_HANDLE, *mut c_void) -> RawStatus,
+    FT_ResetDevicePort: unsafe extern "C" fn(FT_HANDLE) -> RawStatus,
+    FT_CycleDevicePort: unsafe extern "C" fn(FT_HANDLE) -> RawStatus,
+    FT_SetNotificationCallback: unsafe extern "C" fn(FT_HANDLE, extern "C" fn(*mut c_void, ULONG, *mut c_void), *mut c_void) -> RawStatus,
+    FT_ClearNotificationCallback: unsafe extern "C" fn(FT_HANDLE) -> RawStatus,
+    FT_EnableGPIO: unsafe extern "C" fn(FT_HANDLE, ULONG, ULONG) -> RawStatus,
+    FT_WriteGPIO: unsafe extern "C" fn(FT_HANDLE, ULONG, ULONG) -> RawStatus,
+    FT_ReadGPIO: unsafe extern "C" fn(FT_HANDLE, PULONG) -> RawStatus,
+    FT_SetGPIOPull: unsafe extern "C" fn(FT_HANDLE, ULONG, ULONG) -> RawStatus,
+}
+
+/// The resolved symbol table, plus the `Library` it was resolved from.
+///
+/// Bundled together behind a single [`OnceLock`] so the two are always
+/// published atomically: resolving both and then losing a race to set this
+/// just drops `library`/`symbols` together, instead of (as two separate
+/// `OnceLock`s would allow) one thread's `Library` winning while a
+/// different thread's `Symbols` — resolved from its own, now-dropped,
+/// `Library` — wins the other, leaving dangling function pointers.
+struct Loaded {
+    symbols: Symbols,
+    // Kept alive for the lifetime of the process: dropping this `Library`
+    // would unmap the code backing every function pointer in `symbols`.
+    // Declared after `symbols` so it drops after, if this is ever dropped.
+    _library: Library,
+}
+
+static LOADED: OnceLock<Loaded> = OnceLock::new();
+
+/// Load the D3XX driver from `path` (e.g. `libftd3xx.so` or `FTD3XX.dll`)
+/// and resolve every symbol this crate calls into an internal function
+/// table.
+///
+/// Calling this more than once has no effect after the first successful
+/// call. With the `static` feature enabled the library is still opened and
+/// its symbols resolved, but nothing in [`crate::functions`] calls through
+/// them, since the driver is already linked at build time.
+///
+/// # Example
+///
+/// ```no_run
+/// use libftd3xx::dylib::load_dylib;
+///
+/// load_dylib("libftd3xx.so").unwrap();
+/// ```
+pub fn load_dylib<P: AsRef<Path>>(path: P) -> Result<()> {
+    if LOADED.get().is_some() {
+        return Ok(());
+    }
+    let lib = unsafe { Library::new(path.as_ref()) }?;
+    let symbols = unsafe { Symbols::resolve(&lib) }?;
+    // If another thread's `load_dylib` call wins the race, `symbols`/`lib`
+    // are simply dropped together here, never partially published.
+    let _ = LOADED.set(Loaded {
+        symbols,
+        _library: lib,
+    });
+    Ok(())
+}
+
+/// Returns the resolved symbol table, or [`Error::LibraryNotLoaded`] if
+/// [`load_dylib`] has not been called yet.
+pub(crate) fn symbols() -> Result<&'static Symbols> {
+    LOADED.get().map(|loaded| &loaded.symbols).ok_or(Error::LibraryNotLoaded)
+}
@@ -1,13 +1,28 @@
 #![deny(missing_docs)]
 use std::ffi::c_void;
 
-use crate::types::{Error, Result, Version};
+use crate::types::{DeviceInfo, Error, GpioPull, Result, Version};
 ///! Contains the safe versions of functions related to libftd3xx-ffi
 use libftd3xx_ffi::{
     prelude::*, FT_60XCONFIGURATION, FT_DEVICE_LIST_INFO_NODE, FT_HANDLE, LPOVERLAPPED, PULONG,
     UCHAR, ULONG,
 };
 
+/// Calls an `FT_*` entry point.
+///
+/// With the `static` feature enabled this calls straight into
+/// `libftd3xx_ffi`, which linked the driver at build time. Otherwise it
+/// dispatches through the table resolved by [`crate::dylib::load_dylib`],
+/// propagating [`Error::LibraryNotLoaded`] if nothing has been loaded yet.
+macro_rules! ft_call {
+    ($name:ident ( $($arg:expr),* $(,)? )) => {{
+        #[cfg(feature = "static")]
+        { libftd3xx_ffi::$name($($arg),*) }
+        #[cfg(not(feature = "static"))]
+        { (crate::dylib::symbols()?.$name)($($arg),*) }
+    }};
+}
+
 /// Get the D3XX user driver library version number.
 ///
 /// Returns [`FT_OK`] if successful, otherwise the return value is an
@@ -24,11 +39,11 @@ use libftd3xx_ffi::{
 pub fn get_library_version() -> Result<Version> {
     let mut version: u32 = 0;
     //trace!("FT_GetLibraryVersion(_)");
-    let status = unsafe { FT_Status::try_from(FT_GetLibraryVersion(&mut version)) }?;
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_GetLibraryVersion(&mut version))) }?;
     if status == FT_OK {
         return Ok(Version::with_raw(version));
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -41,20 +56,21 @@ pub fn get_library_version() -> Result<Version> {
 ///
 /// ```no_run
 /// use libftd3xx::functions::get_driver_version;
-/// use libftd3xx_ffi::prelude::*;
 /// use libftd3xx::types::Error;
 ///
 /// let version = get_driver_version(std::ptr::null_mut());
-/// assert_eq!(version, Err(Error::APIError(FT_INVALID_HANDLE)));
+/// assert!(matches!(version, Err(Error::InvalidHandle)));
 /// ```
 pub fn get_driver_version(handle: FT_HANDLE) -> Result<Version> {
     let mut version: u32 = 0;
     //trace!("FT_GetLibraryVersion(_)");
-    let status = unsafe { FT_Status::try_from(FT_GetDriverVersion(handle, &mut version)) }?;
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_GetDriverVersion(handle, &mut version)))
+    }?;
     if status == FT_OK {
         return Ok(Version::with_raw(version));
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -79,11 +95,13 @@ pub fn get_driver_version(handle: FT_HANDLE) -> Result<Version> {
 pub fn create_device_info_list() -> Result<u32> {
     let mut num_devices: u32 = 0;
     //trace!("FT_CreateDeviceInfoList(_)");
-    let status = unsafe { FT_Status::try_from(FT_CreateDeviceInfoList(&mut num_devices)) }?;
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_CreateDeviceInfoList(&mut num_devices)))
+    }?;
     if status == FT_OK {
         return Ok(num_devices);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -123,19 +141,96 @@ pub fn get_device_info_list(num_devices: &mut u32) -> Result<Vec<FT_DEVICE_LIST_
     let mut info_list: Vec<FT_DEVICE_LIST_INFO_NODE> = Vec::<FT_DEVICE_LIST_INFO_NODE>::new();
     info_list.resize(*num_devices as usize, FT_DEVICE_LIST_INFO_NODE::default());
     let status = unsafe {
-        FT_Status::try_from(FT_GetDeviceInfoList(
+        FT_Status::try_from(ft_call!(FT_GetDeviceInfoList(
             info_list.as_mut_ptr() as *mut FT_DEVICE_LIST_INFO_NODE,
             num_devices,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(info_list);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
-// TODO: FT_GetDeviceInfoDetail
+/// Returns the number of D3XX devices currently connected to the system.
+///
+/// This is a friendlier name for [`create_device_info_list`], which this
+/// function wraps; calling it also refreshes the device info list used by
+/// [`get_device_info_list`] and [`list_devices`].
+///
+/// # Example
+///
+/// ```no_run
+/// use libftd3xx::functions::num_devices;
+///
+/// let num_devices = num_devices().unwrap();
+/// println!("number of devices: {}", num_devices);
+/// ```
+pub fn num_devices() -> Result<u32> {
+    create_device_info_list()
+}
+
+/// Returns a parsed list of all D3XX devices currently connected to the
+/// system.
+///
+/// This calls [`create_device_info_list`] to refresh the list and
+/// [`get_device_info_list`] to retrieve it, then decodes every raw
+/// [`FT_DEVICE_LIST_INFO_NODE`] into a safe [`DeviceInfo`] so callers never
+/// have to null-trim the C string fields themselves.
+///
+/// # Example
+///
+/// ```no_run
+/// use libftd3xx::functions::list_devices;
+///
+/// for device in list_devices().unwrap() {
+///     println!("{} ({:?})", device.description, device.device_type);
+/// }
+/// ```
+pub fn list_devices() -> Result<Vec<DeviceInfo>> {
+    let mut num_devices = create_device_info_list()?;
+    let info_list = get_device_info_list(&mut num_devices)?;
+    Ok(info_list.into_iter().map(DeviceInfo::from).collect())
+}
+
+/// Returns detailed information for a single device by its index in the
+/// device info list, without allocating a list for every other device.
+///
+/// This calls [`create_device_info_list`] internally to refresh the list, so
+/// `index` must be less than the device count it returns.
+///
+/// # Example
+///
+/// ```no_run
+/// use libftd3xx::functions::get_device_info_detail;
+///
+/// let device = get_device_info_detail(0).unwrap();
+/// println!("{} ({:?})", device.description, device.device_type);
+/// ```
+pub fn get_device_info_detail(index: ULONG) -> Result<DeviceInfo> {
+    create_device_info_list()?;
+    let mut node = FT_DEVICE_LIST_INFO_NODE::default();
+    let mut handle: FT_HANDLE = std::ptr::null_mut();
+    //trace!("FT_GetDeviceInfoDetail(_)");
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_GetDeviceInfoDetail(
+            index,
+            &mut node.Flags,
+            &mut node.Type,
+            &mut node.ID,
+            &mut node.LocId,
+            node.SerialNumber.as_mut_ptr() as *mut c_void,
+            node.Description.as_mut_ptr() as *mut c_void,
+            &mut handle,
+        )))
+    }?;
+    if status == FT_OK {
+        Ok(DeviceInfo::from(node))
+    } else {
+        Err(Error::from(status))
+    }
+}
 
 /// Open the device and return a handle which will be used for subsequent accesses.
 ///
@@ -162,11 +257,13 @@ pub fn create_by_index(index: libftd3xx_ffi::ULONG) -> Result<FT_HANDLE> {
     //trace!("FT_Create(_)");
     let mut handle: FT_HANDLE = std::ptr::null_mut();
     let pv_arg = index as *mut std::ffi::c_ulong as *mut std::ffi::c_void;
-    let status = unsafe { FT_Status::try_from(FT_Create(pv_arg, FT_OPEN_BY_INDEX, &mut handle)) }?;
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_Create(pv_arg, FT_OPEN_BY_INDEX, &mut handle)))
+    }?;
     if status == FT_OK {
         return Ok(handle);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -202,16 +299,16 @@ pub fn create_by_serial_number<S: Into<String>>(serial: S) -> Result<FT_HANDLE>
     }
 
     let status = unsafe {
-        FT_Status::try_from(FT_Create(
+        FT_Status::try_from(ft_call!(FT_Create(
             buffer.as_mut_ptr() as *mut std::ffi::c_void,
             FT_OPEN_BY_SERIAL_NUMBER,
             &mut handle,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(handle);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -247,16 +344,16 @@ pub fn create_by_description<S: Into<String>>(description: S) -> Result<FT_HANDL
     }
 
     let status = unsafe {
-        FT_Status::try_from(FT_Create(
+        FT_Status::try_from(ft_call!(FT_Create(
             buffer.as_mut_ptr() as *mut std::ffi::c_void,
             FT_OPEN_BY_DESCRIPTION,
             &mut handle,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(handle);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -276,11 +373,11 @@ pub fn create_by_description<S: Into<String>>(description: S) -> Result<FT_HANDL
 pub fn close(handle: FT_HANDLE) -> Result<()> {
     //trace!("FT_Create(_)");
 
-    let status = unsafe { FT_Status::try_from(FT_Close(handle)) }?;
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_Close(handle))) }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -318,23 +415,43 @@ pub fn write_pipe(
     buffer: &mut Vec<u8>,
     p_overlapped: LPOVERLAPPED,
 ) -> Result<ULONG> {
+    let (bytes_transfered, result) = write_pipe_partial(handle, uc_pipe_id, buffer, p_overlapped);
+    result.map(|()| bytes_transfered)
+}
+
+/// Like [`write_pipe`], but returns the driver's `bytes_transfered` output
+/// alongside the result instead of discarding it on error — on
+/// `Err(Error::Timeout)` the driver still reports how much of `buffer` made
+/// it out before the timeout elapsed.
+///
+/// See [`crate::types::Device::write_all`], which needs that partial count
+/// to report an accurate [`crate::types::TimeoutError::Timeout`].
+pub(crate) fn write_pipe_partial(
+    handle: FT_HANDLE,
+    uc_pipe_id: u8,
+    buffer: &mut Vec<u8>,
+    p_overlapped: LPOVERLAPPED,
+) -> (ULONG, Result<()>) {
     //trace!("FT_WritePipe(_)");
     let mut bytes_transfered: ULONG = 0;
-    let status = unsafe {
-        FT_Status::try_from(FT_WritePipe(
-            handle,
-            uc_pipe_id as UCHAR,
-            buffer.as_mut_ptr(),
-            buffer.len() as ULONG,
-            &mut bytes_transfered as PULONG,
-            p_overlapped,
-        ))
-    }?;
-    if status == FT_OK {
-        return Ok(bytes_transfered);
-    } else {
-        return Err(Error::APIError(status));
-    }
+    let result = (|| {
+        let status = unsafe {
+            FT_Status::try_from(ft_call!(FT_WritePipe(
+                handle,
+                uc_pipe_id as UCHAR,
+                buffer.as_mut_ptr(),
+                buffer.len() as ULONG,
+                &mut bytes_transfered as PULONG,
+                p_overlapped,
+            )))
+        }?;
+        if status == FT_OK {
+            Ok(())
+        } else {
+            Err(Error::from(status))
+        }
+    })();
+    (bytes_transfered, result)
 }
 
 /// Read data from pipe.
@@ -378,23 +495,43 @@ pub fn read_pipe(
     buffer: &mut Vec<u8>,
     p_overlapped: LPOVERLAPPED,
 ) -> Result<ULONG> {
+    let (bytes_transfered, result) = read_pipe_partial(handle, uc_pipe_id, buffer, p_overlapped);
+    result.map(|()| bytes_transfered)
+}
+
+/// Like [`read_pipe`], but returns the driver's `bytes_transfered` output
+/// alongside the result instead of discarding it on error — on
+/// `Err(Error::Timeout)` the driver still reports how much of `buffer` it
+/// filled before the timeout elapsed.
+///
+/// See [`crate::types::Device::read_all`], which needs that partial count
+/// to report an accurate [`crate::types::TimeoutError::Timeout`].
+pub(crate) fn read_pipe_partial(
+    handle: FT_HANDLE,
+    uc_pipe_id: u8,
+    buffer: &mut Vec<u8>,
+    p_overlapped: LPOVERLAPPED,
+) -> (ULONG, Result<()>) {
     //trace!("FT_ReadPipe(_)");
     let mut bytes_transfered: ULONG = 0;
-    let status = unsafe {
-        FT_Status::try_from(FT_ReadPipe(
-            handle,
-            uc_pipe_id as UCHAR,
-            buffer.as_mut_ptr(),
-            buffer.len() as ULONG,
-            &mut bytes_transfered as PULONG,
-            p_overlapped,
-        ))
-    }?;
-    if status == FT_OK {
-        return Ok(bytes_transfered);
-    } else {
-        return Err(Error::APIError(status));
-    }
+    let result = (|| {
+        let status = unsafe {
+            FT_Status::try_from(ft_call!(FT_ReadPipe(
+                handle,
+                uc_pipe_id as UCHAR,
+                buffer.as_mut_ptr(),
+                buffer.len() as ULONG,
+                &mut bytes_transfered as PULONG,
+                p_overlapped,
+            )))
+        }?;
+        if status == FT_OK {
+            Ok(())
+        } else {
+            Err(Error::from(status))
+        }
+    })();
+    (bytes_transfered, result)
 }
 
 /// Writes data to the pipe. FT_WritePipeEx has much lower latency compared to FT_WritePipe when
@@ -436,19 +573,19 @@ pub fn write_pipe_ex(
     //trace!("FT_WritePipe(_)");
     let mut bytes_transfered: ULONG = 0;
     let status = unsafe {
-        FT_Status::try_from(FT_WritePipeEx(
+        FT_Status::try_from(ft_call!(FT_WritePipeEx(
             handle,
             uc_pipe_id as UCHAR,
             buffer.as_mut_ptr(),
             buffer.len() as ULONG,
             &mut bytes_transfered as PULONG,
             p_overlapped,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(bytes_transfered);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -498,19 +635,19 @@ pub fn read_pipe_ex(
     //trace!("FT_ReadPipe(_)");
     let mut bytes_transfered: ULONG = 0;
     let status = unsafe {
-        FT_Status::try_from(FT_ReadPipeEx(
+        FT_Status::try_from(ft_call!(FT_ReadPipeEx(
             handle,
             uc_pipe_id as UCHAR,
             buffer.as_mut_ptr(),
             buffer.len() as ULONG,
             &mut bytes_transfered as PULONG,
             p_overlapped,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(bytes_transfered);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 /// Retrieves the result of an overlapped operation to a pipe
@@ -540,17 +677,17 @@ pub fn get_overlapped_result(
     //trace!("FT_GetOverlappedResult(_)");
     let mut bytes_transfered: ULONG = 0;
     let status = unsafe {
-        FT_Status::try_from(FT_GetOverlappedResult(
+        FT_Status::try_from(ft_call!(FT_GetOverlappedResult(
             handle,
             p_overlapped,
             &mut bytes_transfered as PULONG,
             b_wait.into(),
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(bytes_transfered);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -573,11 +710,13 @@ pub fn get_overlapped_result(
 /// ```
 pub fn initialize_overlapped(handle: FT_HANDLE, p_overlapped: LPOVERLAPPED) -> Result<()> {
     //trace!("FT_InitializeOverlapped(_)");
-    let status = unsafe { FT_Status::try_from(FT_InitializeOverlapped(handle, p_overlapped)) }?;
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_InitializeOverlapped(handle, p_overlapped)))
+    }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -600,11 +739,13 @@ pub fn initialize_overlapped(handle: FT_HANDLE, p_overlapped: LPOVERLAPPED) -> R
 /// ```
 pub fn release_overlapped(handle: FT_HANDLE, p_overlapped: LPOVERLAPPED) -> Result<()> {
     //trace!("FT_ReleaseOverlapped(_)");
-    let status = unsafe { FT_Status::try_from(FT_ReleaseOverlapped(handle, p_overlapped)) }?;
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_ReleaseOverlapped(handle, p_overlapped)))
+    }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -632,18 +773,18 @@ pub fn set_stream_pipe(
 ) -> Result<()> {
     //trace!("FT_SetStreamPipe(_)");
     let status = unsafe {
-        FT_Status::try_from(FT_SetStreamPipe(
+        FT_Status::try_from(ft_call!(FT_SetStreamPipe(
             handle,
             b_all_write_pipes.into(),
             b_all_read_pipes.into(),
             uc_pipe_id.into(),
             ul_stream_size,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -669,17 +810,17 @@ pub fn clear_stream_pipe(
 ) -> Result<()> {
     //trace!("FT_ClearStreamPipe(_)");
     let status = unsafe {
-        FT_Status::try_from(FT_ClearStreamPipe(
+        FT_Status::try_from(ft_call!(FT_ClearStreamPipe(
             handle,
             b_all_write_pipes.into(),
             b_all_read_pipes.into(),
             uc_pipe_id.into(),
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -707,16 +848,16 @@ pub fn set_pipe_timeout(
 ) -> Result<()> {
     //trace!("FT_SetPipeTimeout(_)");
     let status = unsafe {
-        FT_Status::try_from(FT_SetPipeTimeout(
+        FT_Status::try_from(ft_call!(FT_SetPipeTimeout(
             handle,
             uc_pipe_id.into(),
             ul_timeout_in_ms,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -752,7 +893,7 @@ pub fn get_pipe_timeout(
     if status == FT_OK {
         return Ok(ul_timeout_in_ms);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
  */
@@ -780,15 +921,15 @@ pub fn abort_pipe(
 ) -> Result<()> {
     //trace!("FT_AbortPipe(_)");
     let status = unsafe {
-        FT_Status::try_from(FT_AbortPipe(
+        FT_Status::try_from(ft_call!(FT_AbortPipe(
             handle,
             uc_pipe_id.into(),
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 // todo: FT_GetDeviceDescriptor
@@ -797,8 +938,53 @@ pub fn abort_pipe(
 // todo: FT_GetStringDescriptor
 // todo: FT_GetDescriptor
 // todo: FT_ControlTransfer
-// todo: FT_SetNotificationCallback
-// todo: FT_ClearNotificationCallback
+/// The raw `extern "C"` shape of a D3XX notification callback, taking the
+/// `pvArgs` context pointer, the `E_FT_NOTIFICATION_CALLBACK_TYPE` value,
+/// and a type-dependent `pvCallbackData` pointer.
+pub(crate) type RawNotificationCallback =
+    extern "C" fn(*mut c_void, ULONG, *mut c_void);
+
+/// Registers `callback` to be invoked by the driver on notification events
+/// (data available on a pipe, or a GPIO line change), with `context` passed
+/// back as its first argument.
+///
+/// This is a thin wrapper over `FT_SetNotificationCallback`; see
+/// [`crate::types::Device::set_notification_callback`] for a safe,
+/// closure-based API built on top of it.
+///
+/// Returns [`FT_OK`] if successful, otherwise the return value is an
+/// FT error code. See [`FT_Status`] for more information.
+pub fn set_notification_callback(
+    handle: FT_HANDLE,
+    callback: RawNotificationCallback,
+    context: *mut c_void,
+) -> Result<()> {
+    //trace!("FT_SetNotificationCallback(_)");
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_SetNotificationCallback(
+            handle, callback, context,
+        )))
+    }?;
+    if status == FT_OK {
+        return Ok(());
+    } else {
+        return Err(Error::from(status));
+    }
+}
+
+/// Unregisters the callback set by [`set_notification_callback`], if any.
+///
+/// Returns [`FT_OK`] if successful, otherwise the return value is an
+/// FT error code. See [`FT_Status`] for more information.
+pub fn clear_notification_callback(handle: FT_HANDLE) -> Result<()> {
+    //trace!("FT_ClearNotificationCallback(_)");
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_ClearNotificationCallback(handle))) }?;
+    if status == FT_OK {
+        return Ok(());
+    } else {
+        return Err(Error::from(status));
+    }
+}
 
 /// Returns the chip configuration.
 ///
@@ -841,15 +1027,15 @@ pub fn get_chip_configuration(handle: FT_HANDLE) -> Result<FT_60XCONFIGURATION>
     //trace!("FT_GetChipConfiguration(_)");
     let mut config = FT_60XCONFIGURATION::default();
     let status = unsafe {
-        FT_Status::try_from(FT_GetChipConfiguration(
+        FT_Status::try_from(ft_call!(FT_GetChipConfiguration(
             handle,
             &mut config as *mut _ as *mut c_void,
-        ))
+        )))
     }?;
     if status == FT_OK {
         return Ok(config);
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
@@ -882,14 +1068,14 @@ pub fn set_chip_configuration(
     //trace!("FT_SetChipConfiguration(_)");
     // pvConfiguration can be NULL, which will reset the configuration to defaults
     let config = match &config {
-        Some(mut c) => &mut c as *mut _ as *mut c_void,
+        Some(c) => c as *const FT_60XCONFIGURATION as *mut c_void,
         None => std::ptr::null_mut() as *mut c_void,
     };
-    let status = unsafe { FT_Status::try_from(FT_GetChipConfiguration(handle, config)) }?;
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_SetChipConfiguration(handle, config))) }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 // todo: FT_GetFirmwareVersion
@@ -911,11 +1097,11 @@ pub fn set_chip_configuration(
 pub fn reset_device_port(handle: FT_HANDLE) -> Result<()> {
     //trace!("FT_ResetDevicePort(_)");
 
-    let status = unsafe { FT_Status::try_from(FT_ResetDevicePort(handle)) }?;
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_ResetDevicePort(handle))) }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 // todo: FT_CycleDevicePort
@@ -936,30 +1122,95 @@ pub fn reset_device_port(handle: FT_HANDLE) -> Result<()> {
 pub fn cycle_device_port(handle: FT_HANDLE) -> Result<()> {
     //trace!("FT_CycleDevicePort(_)");
 
-    let status = unsafe { FT_Status::try_from(FT_CycleDevicePort(handle)) }?;
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_CycleDevicePort(handle))) }?;
     if status == FT_OK {
         return Ok(());
     } else {
-        return Err(Error::APIError(status));
+        return Err(Error::from(status));
     }
 }
 
-// todo: FT_CreateDeviceInfoList
-// todo: FT_GetDeviceInfoList
-// todo: FT_GetDeviceInfoDetail
 // todo: FT_ListDevices
 // todo: FT_IsDevicePath
-// todo: FT_GetDriverVersion
-// todo: FT_GetLibraryVersion
-// todo: FT_EnableGPIO
-// todo: FT_WriteGPIO
-// todo: FT_ReadGPIO
-// todo: FT_SetGPIOPull
+
+/// Enables GPIO mode on the lines selected by `mask`, with `direction`
+/// marking which of those lines are outputs (vs. inputs).
+///
+/// See [`crate::types::Device::enable_gpio`] for a safe, per-pin API built
+/// on top of this.
+///
+/// Returns [`FT_OK`] if successful, otherwise the return value is an
+/// FT error code. See [`FT_Status`] for more information.
+pub fn enable_gpio(handle: FT_HANDLE, mask: ULONG, direction: ULONG) -> Result<()> {
+    //trace!("FT_EnableGPIO(_)");
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_EnableGPIO(handle, mask, direction))) }?;
+    if status == FT_OK {
+        return Ok(());
+    } else {
+        return Err(Error::from(status));
+    }
+}
+
+/// Writes `data` to the GPIO lines selected by `mask`; lines outside `mask`
+/// are left unchanged.
+///
+/// See [`crate::types::Device::write_gpio`] for a safe, per-pin API built
+/// on top of this.
+///
+/// Returns [`FT_OK`] if successful, otherwise the return value is an
+/// FT error code. See [`FT_Status`] for more information.
+pub fn write_gpio(handle: FT_HANDLE, mask: ULONG, data: ULONG) -> Result<()> {
+    //trace!("FT_WriteGPIO(_)");
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_WriteGPIO(handle, mask, data))) }?;
+    if status == FT_OK {
+        return Ok(());
+    } else {
+        return Err(Error::from(status));
+    }
+}
+
+/// Reads the current level of every GPIO line, packed into the low bits of
+/// the returned value.
+///
+/// See [`crate::types::Device::read_gpio`] for a safe, decoded API built on
+/// top of this.
+///
+/// Returns [`FT_OK`] if successful, otherwise the return value is an
+/// FT error code. See [`FT_Status`] for more information.
+pub fn read_gpio(handle: FT_HANDLE) -> Result<ULONG> {
+    //trace!("FT_ReadGPIO(_)");
+    let mut value: ULONG = 0;
+    let status = unsafe { FT_Status::try_from(ft_call!(FT_ReadGPIO(handle, &mut value))) }?;
+    if status == FT_OK {
+        return Ok(value);
+    } else {
+        return Err(Error::from(status));
+    }
+}
+
+/// Sets the pull configuration (none/pull-down/pull-up) for the GPIO lines
+/// selected by `mask`.
+///
+/// See [`crate::types::Device::set_gpio_pull`] for a safe, typed API built
+/// on top of this.
+///
+/// Returns [`FT_OK`] if successful, otherwise the return value is an
+/// FT error code. See [`FT_Status`] for more information.
+pub fn set_gpio_pull(handle: FT_HANDLE, mask: ULONG, pull: GpioPull) -> Result<()> {
+    //trace!("FT_SetGPIOPull(_)");
+    let status = unsafe {
+        FT_Status::try_from(ft_call!(FT_SetGPIOPull(handle, mask, ULONG::from(pull))))
+    }?;
+    if status == FT_OK {
+        return Ok(());
+    } else {
+        return Err(Error::from(status));
+    }
+}
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::functions::Error::APIError;
     use std::ffi::CStr;
     use std::{thread, time};
 
@@ -1010,9 +1261,9 @@ mod tests {
             // Open the handle, sometimes we get some errors so lets retry here...
             handle = match create_by_index(0) {
                 Ok(h) => h,
-                Err(APIError(FT_DEVICE_NOT_OPENED))
-                | Err(APIError(FT_DEVICE_NOT_FOUND))
-                | Err(APIError(FT_OTHER_ERROR)) => continue,
+                Err(Error::DeviceNotOpened) | Err(Error::DeviceNotFound) | Err(Error::OtherError) => {
+                    continue
+                }
                 Err(e) => panic!("create_by_index(0) failed: {e}"),
             };
             break;
@@ -1048,9 +1299,7 @@ mod tests {
             // Open the handle, sometimes we get FT_DEVICE_NOT_OPENED so lets retry here...
             handle = match create_by_serial_number(&sn) {
                 Ok(h) => h,
-                Err(APIError(FT_DEVICE_NOT_OPENED)) | Err(APIError(FT_DEVICE_NOT_FOUND)) => {
-                    continue
-                }
+                Err(Error::DeviceNotOpened) | Err(Error::DeviceNotFound) => continue,
                 Err(e) => panic!("create_by_serial_number({sn}) failed: {e}"),
             };
             break;
@@ -1062,10 +1311,8 @@ mod tests {
     //#[cfg(not(feature = "hardware_tests"))]
     #[test]
     fn test_get_driver_version_invalid() {
-        use crate::functions::Error::APIError;
-
         let result = get_driver_version(std::ptr::null_mut());
-        assert_eq!(result, Err(APIError(FT_INVALID_HANDLE)));
+        assert!(matches!(result, Err(Error::InvalidHandle)));
     }
 
     #[cfg(feature = "hardware_tests")]
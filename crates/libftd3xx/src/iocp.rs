@@ -0,0 +1,386 @@
+#![cfg(windows)]
+//! Asynchronous FT60x pipe transfers driven by a Windows I/O completion
+//! port, for use from `tokio` or any other `std::future`-compatible
+//! executor.
+//!
+//! [`crate::types::Overlapped`]/[`crate::types::TransferRing`] leave the
+//! caller to block in [`crate::types::Overlapped::wait`] or repeatedly call
+//! [`crate::types::Overlapped::poll`] for each outstanding transfer. This
+//! module instead associates a [`Device`]'s handle with an
+//! IOCP and runs a single background reactor thread that blocks on
+//! `GetQueuedCompletionStatus`, matching each completion back to the
+//! [`Waker`] of the future that submitted it — the same IOCP-to-readiness
+//! translation mio's Windows named pipe backend uses for its reactor.
+
+use std::collections::HashMap;
+use std::future::Future;
+use std::os::windows::io::RawHandle;
+use std::pin::Pin;
+use std::sync::{Arc, Condvar, Mutex};
+use std::task::{Context, Poll, Waker};
+use std::thread::JoinHandle;
+
+use libftd3xx_ffi::{LPOVERLAPPED, OVERLAPPED, ULONG};
+
+use crate::types::{Device, Error, Result};
+
+#[allow(non_snake_case)]
+#[link(name = "kernel32")]
+extern "system" {
+    fn CreateIoCompletionPort(
+        file_handle: RawHandle,
+        existing_completion_port: RawHandle,
+        completion_key: usize,
+        number_of_concurrent_threads: u32,
+    ) -> RawHandle;
+
+    fn GetQueuedCompletionStatus(
+        completion_port: RawHandle,
+        lp_number_of_bytes_transferred: *mut u32,
+        lp_completion_key: *mut usize,
+        lp_overlapped: *mut LPOVERLAPPED,
+        dw_milliseconds: u32,
+    ) -> i32;
+
+    fn PostQueuedCompletionStatus(
+        completion_port: RawHandle,
+        dw_number_of_bytes_transferred: u32,
+        dw_completion_key: usize,
+        lp_overlapped: LPOVERLAPPED,
+    ) -> i32;
+
+    fn CloseHandle(handle: RawHandle) -> i32;
+}
+
+/// Identifies one in-flight transfer to the reactor thread.
+type Token = usize;
+
+/// A `RawHandle` (`*mut c_void`) is not `Send` on its own, even though
+/// Windows handles are safe to use from any thread once created; this
+/// wraps one so it can be moved into the reactor thread spawned by
+/// [`CompletionPort::new`].
+struct SendHandle(RawHandle);
+
+// SAFETY: a `RawHandle` is an opaque handle value; Windows permits calling
+// completion-port APIs on it from any thread, and this crate never treats
+// it as `!Send` data.
+unsafe impl Send for SendHandle {}
+
+/// Shutdown sentinel posted to the completion port by [`CompletionPort::drop`].
+const SHUTDOWN_KEY: usize = usize::MAX;
+
+/// A transfer's `OVERLAPPED`, tagged with the [`Token`] the reactor uses to
+/// look up its waker once the driver completes it.
+///
+/// `LPOVERLAPPED` is a pointer to the first field, so a pointer to this
+/// struct can stand in for a `LPOVERLAPPED` everywhere the D3XX API expects
+/// one; the reactor casts it back to recover `token`.
+#[repr(C)]
+struct TaggedOverlapped {
+    raw: OVERLAPPED,
+    token: Token,
+}
+
+struct TransferState {
+    waker: Option<Waker>,
+    result: Option<Result<ULONG>>,
+}
+
+/// A transfer's state plus a [`Condvar`] so that
+/// [`PipeTransfer::drop`][Drop::drop] can block the dropping thread until
+/// the reactor has observed the completion of an aborted transfer, instead
+/// of only supporting the [`Waker`]-based notification a polling future
+/// uses.
+struct TransferSlot {
+    state: Mutex<TransferState>,
+    ready: Condvar,
+}
+
+struct Shared {
+    states: Mutex<HashMap<Token, Arc<TransferSlot>>>,
+    next_token: Mutex<Token>,
+}
+
+/// An I/O completion port associated with one [`Device`]'s handle, backed by
+/// a background reactor thread.
+///
+/// Submit transfers with [`CompletionPort::read`]/[`CompletionPort::write`];
+/// each returns a [`PipeTransfer`] future. Dropping a `CompletionPort` stops
+/// the reactor thread and closes the port.
+pub struct CompletionPort {
+    port: RawHandle,
+    shared: Arc<Shared>,
+    reactor: Option<JoinHandle<()>>,
+}
+
+// The port handle is only passed to Win32 completion-port calls, which may be
+// made from any thread; the `states` map is only ever touched through
+// `Arc<Mutex<_>>`.
+unsafe impl Send for CompletionPort {}
+unsafe impl Sync for CompletionPort {}
+
+impl CompletionPort {
+    /// Creates a completion port, associates `device`'s handle with it, and
+    /// starts the reactor thread.
+    pub fn new(device: &Device) -> Result<Self> {
+        let port = unsafe {
+            CreateIoCompletionPort(std::ptr::null_mut(), std::ptr::null_mut(), 0, 1)
+        };
+        if port.is_null() {
+            return Err(Error::from("CreateIoCompletionPort: failed to create port"));
+        }
+        let associated = unsafe {
+            CreateIoCompletionPort(device.handle() as RawHandle, port, 0, 0)
+        };
+        if associated.is_null() {
+            unsafe { CloseHandle(port) };
+            return Err(Error::from(
+                "CreateIoCompletionPort: failed to associate device handle",
+            ));
+        }
+
+        let shared = Arc::new(Shared {
+            states: Mutex::new(HashMap::new()),
+            next_token: Mutex::new(0),
+        });
+        let reactor_shared = Arc::clone(&shared);
+        let reactor_port = SendHandle(port);
+        let reactor = std::thread::spawn(move || reactor_loop(reactor_port.0, reactor_shared));
+
+        Ok(CompletionPort {
+            port,
+            shared,
+            reactor: Some(reactor),
+        })
+    }
+
+    fn register(&self) -> (Token, Arc<TransferSlot>) {
+        let mut next_token = self.shared.next_token.lock().unwrap();
+        let token = *next_token;
+        *next_token += 1;
+        drop(next_token);
+
+        let slot = Arc::new(TransferSlot {
+            state: Mutex::new(TransferState {
+                waker: None,
+                result: None,
+            }),
+            ready: Condvar::new(),
+        });
+        self.shared
+            .states
+            .lock()
+            .unwrap()
+            .insert(token, Arc::clone(&slot));
+        (token, slot)
+    }
+
+    /// Submits an asynchronous read on `pipe_id`, returning a future that
+    /// resolves to `buffer` truncated to however many bytes were read.
+    pub fn read<'a>(&'a self, device: &'a Device, pipe_id: u8, buffer: Vec<u8>) -> PipeTransfer<'a> {
+        PipeTransfer::new(device, self, pipe_id, buffer, Direction::Read)
+    }
+
+    /// Submits an asynchronous write on `pipe_id`, returning a future that
+    /// resolves to `buffer` and the number of bytes written.
+    pub fn write<'a>(&'a self, device: &'a Device, pipe_id: u8, buffer: Vec<u8>) -> PipeTransfer<'a> {
+        PipeTransfer::new(device, self, pipe_id, buffer, Direction::Write)
+    }
+}
+
+impl Drop for CompletionPort {
+    fn drop(&mut self) {
+        // Wake the reactor out of `GetQueuedCompletionStatus` and wait for
+        // it to exit before closing the port it is blocked on.
+        unsafe {
+            PostQueuedCompletionStatus(self.port, 0, SHUTDOWN_KEY, std::ptr::null_mut());
+        }
+        if let Some(reactor) = self.reactor.take() {
+            let _ = reactor.join();
+        }
+        unsafe { CloseHandle(self.port) };
+    }
+}
+
+fn reactor_loop(port: RawHandle, shared: Arc<Shared>) {
+    loop {
+        let mut bytes_transferred: u32 = 0;
+        let mut completion_key: usize = 0;
+        let mut overlapped: LPOVERLAPPED = std::ptr::null_mut();
+
+        let ok = unsafe {
+            GetQueuedCompletionStatus(
+                port,
+                &mut bytes_transferred,
+                &mut completion_key,
+                &mut overlapped,
+                u32::MAX,
+            )
+        };
+
+        if completion_key == SHUTDOWN_KEY {
+            return;
+        }
+        if overlapped.is_null() {
+            continue;
+        }
+
+        let tagged = overlapped as *mut TaggedOverlapped;
+        let token = unsafe { (*tagged).token };
+        let result = if ok != 0 {
+            Ok(bytes_transferred as ULONG)
+        } else {
+            Err(Error::IoError)
+        };
+
+        if let Some(slot) = shared.states.lock().unwrap().remove(&token) {
+            let mut state = slot.state.lock().unwrap();
+            state.result = Some(result);
+            let waker = state.waker.take();
+            drop(state);
+            slot.ready.notify_all();
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }
+    }
+}
+
+enum Direction {
+    Read,
+    Write,
+}
+
+/// A future resolving to the buffer and byte count of one `FT_ReadPipeEx`/
+/// `FT_WritePipeEx` transfer submitted against a [`CompletionPort`].
+///
+/// The `OVERLAPPED` and its backing buffer are boxed and kept pinned for the
+/// lifetime of the transfer. If this future is dropped before completion,
+/// [`Device::abort_pipe`] is called, and the drop blocks until the reactor
+/// thread observes the abort's completion, before the buffer and
+/// `OVERLAPPED` are freed — otherwise the reactor could still be dereferencing
+/// them when `GetQueuedCompletionStatus` hands back the completion the abort
+/// generates.
+pub struct PipeTransfer<'a> {
+    device: &'a Device,
+    port: &'a CompletionPort,
+    pipe_id: u8,
+    token: Token,
+    state: Arc<TransferSlot>,
+    tagged: Box<TaggedOverlapped>,
+    buffer: Option<Vec<u8>>,
+    direction: Direction,
+    submitted: bool,
+}
+
+// SAFETY: `PipeTransfer` is not auto-`Send` because `Device` is `!Sync`
+// (it holds a `Cell`) and `TaggedOverlapped` wraps a raw `OVERLAPPED`. But
+// `PipeTransfer` never shares `&'a Device`/`tagged` across threads
+// concurrently — it accesses `device` only to issue a single FFI call at a
+// time (from whichever thread currently owns the future) and `tagged`'s
+// `OVERLAPPED` is never touched by this struct's own thread once submitted
+// (only the reactor thread reads it via the `TaggedOverlapped*` recovered
+// from `GetQueuedCompletionStatus`, synchronized through `TransferSlot`'s
+// `Mutex`/`Condvar`). So moving an entire `PipeTransfer` to another thread,
+// e.g. for `tokio::spawn` on a multi-thread runtime, is sound.
+unsafe impl<'a> Send for PipeTransfer<'a> {}
+
+impl<'a> PipeTransfer<'a> {
+    fn new(
+        device: &'a Device,
+        port: &'a CompletionPort,
+        pipe_id: u8,
+        buffer: Vec<u8>,
+        direction: Direction,
+    ) -> Self {
+        let (token, state) = port.register();
+        PipeTransfer {
+            device,
+            port,
+            pipe_id,
+            token,
+            state,
+            tagged: Box::new(TaggedOverlapped {
+                raw: OVERLAPPED::default(),
+                token,
+            }),
+            buffer: Some(buffer),
+            direction,
+            submitted: false,
+        }
+    }
+}
+
+impl<'a> Future for PipeTransfer<'a> {
+    /// The (possibly truncated, for reads) buffer and the number of bytes
+    /// transferred.
+    type Output = Result<(Vec<u8>, ULONG)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let this = self.get_mut();
+
+        if !this.submitted {
+            let p_overlapped = &mut this.tagged.raw as *mut OVERLAPPED;
+            let buffer = this
+                .buffer
+                .as_mut()
+                .expect("PipeTransfer buffer taken before submission");
+            let submit = match this.direction {
+                Direction::Read => {
+                    crate::functions::read_pipe_ex(this.device.handle(), this.pipe_id, buffer, p_overlapped)
+                }
+                Direction::Write => {
+                    crate::functions::write_pipe_ex(this.device.handle(), this.pipe_id, buffer, p_overlapped)
+                }
+            };
+            match submit {
+                Ok(_) | Err(Error::IoPending) => this.submitted = true,
+                // The driver rejected the transfer, so no completion will
+                // ever reach the reactor for this token.
+                Err(e) => return Poll::Ready(Err(e)),
+            }
+        }
+
+        let mut state = this.state.state.lock().unwrap();
+        if let Some(result) = state.result.take() {
+            drop(state);
+            let bytes_transferred = result?;
+            let mut buffer = this.buffer.take().expect("buffer already taken");
+            if matches!(this.direction, Direction::Read) {
+                buffer.truncate(bytes_transferred as usize);
+            }
+            return Poll::Ready(Ok((buffer, bytes_transferred)));
+        }
+        state.waker = Some(cx.waker().clone());
+        Poll::Pending
+    }
+}
+
+impl<'a> Drop for PipeTransfer<'a> {
+    fn drop(&mut self) {
+        if !self.submitted {
+            // The reactor has never heard of this token; nothing to wait
+            // for, just drop our own map entry.
+            self.port.shared.states.lock().unwrap().remove(&self.token);
+            return;
+        }
+
+        let mut state = self.state.state.lock().unwrap();
+        if state.result.is_some() {
+            return;
+        }
+
+        let _ = self.device.abort_pipe(self.pipe_id);
+        // Block until the reactor thread has dequeued the completion the
+        // abort generates and recorded a result here. Only then is it safe
+        // to let `self.tagged`/`self.buffer` be freed below: until that
+        // point the reactor may still dereference the (otherwise freed)
+        // `TaggedOverlapped` via the raw `LPOVERLAPPED` it gets back from
+        // `GetQueuedCompletionStatus`. The reactor removes our entry from
+        // `shared.states` itself once it delivers that completion, so there
+        // is nothing left to clean up here afterward.
+        while state.result.is_none() {
+            state = self.state.ready.wait(state).unwrap();
+        }
+    }
+}
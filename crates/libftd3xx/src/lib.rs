@@ -1,9 +1,20 @@
 //#![deny(missing_docs, unsafe_code)]
-//! The D3XX interface is a proprietary interface specifically for FTDI SuperSpeed USB devices (FT60x series). 
-//! D3XX implements a proprietary protocol different from D2XX in order to maximize USB 3.0 bandwidth. 
+//! The D3XX interface is a proprietary interface specifically for FTDI SuperSpeed USB devices (FT60x series).
+//! D3XX implements a proprietary protocol different from D2XX in order to maximize USB 3.0 bandwidth.
+//!
+//! By default the driver is resolved at runtime; see [`dylib`] for
+//! [`dylib::load_dylib`]. Enable the `static` cargo feature to link the
+//! driver at build time instead.
 
 /// struct/enum/constant objects/values
 pub mod types;
 
 /// Safe ftd3xx functions
-pub mod functions;
\ No newline at end of file
+pub mod functions;
+
+/// Runtime loading of the D3XX driver library
+pub mod dylib;
+
+/// IOCP-backed asynchronous pipe transfers (Windows only)
+#[cfg(windows)]
+pub mod iocp;
\ No newline at end of file
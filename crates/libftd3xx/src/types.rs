@@ -1,37 +1,212 @@
-#![deny(missing_docs, unsafe_code)]
+#![deny(missing_docs)]
 ///! Contains higher level objects and or constant values.
 use libftd3xx_ffi::prelude::*;
+use libftd3xx_ffi::{
+    FT_60XCONFIGURATION, FT_DEVICE_LIST_INFO_NODE, FT_HANDLE,
+    FT_NOTIFICATION_CALLBACK_INFO_DATA, FT_NOTIFICATION_CALLBACK_INFO_GPIO, LPOVERLAPPED,
+    OVERLAPPED, UCHAR, ULONG,
+};
 use core::fmt;
+use std::ffi::CStr;
 
-/// Errors associated with this library
-#[derive(Debug)]
+/// Errors associated with this library.
+///
+/// Every public function returns one of these variants instead of a raw
+/// [`FT_Status`], so callers can match on a specific failure mode (e.g.
+/// [`Error::Timeout`]) instead of comparing integers. Construct one from a
+/// driver status code with `Error::from(status)`.
+#[derive(Debug, thiserror::Error)]
 pub enum Error {
-    /// Low level API error directly from the FTD3xx Library
-    APIError(FT_Status),
-    /// Low level Critical API error, this is something that would normally "panic"
-    CriticalAPIError(String),
-}
+    /// `FT_INVALID_HANDLE`: the supplied handle was invalid.
+    #[error("FT_INVALID_HANDLE")]
+    InvalidHandle,
+    /// `FT_DEVICE_NOT_FOUND`: the device could not be found.
+    #[error("FT_DEVICE_NOT_FOUND")]
+    DeviceNotFound,
+    /// `FT_DEVICE_NOT_OPENED`: the device is not open.
+    #[error("FT_DEVICE_NOT_OPENED")]
+    DeviceNotOpened,
+    /// `FT_IO_ERROR`: an I/O error occurred while communicating with the device.
+    #[error("FT_IO_ERROR")]
+    IoError,
+    /// `FT_INSUFFICIENT_RESOURCES`: insufficient resources were available.
+    #[error("FT_INSUFFICIENT_RESOURCES")]
+    InsufficientResources,
+    /// `FT_INVALID_PARAMETER`: an invalid parameter was supplied.
+    #[error("FT_INVALID_PARAMETER")]
+    InvalidParameter,
+    /// `FT_INVALID_BAUD_RATE`: an invalid baud rate was supplied.
+    #[error("FT_INVALID_BAUD_RATE")]
+    InvalidBaudRate,
+    /// `FT_DEVICE_NOT_OPENED_FOR_ERASE`: the device is not opened for erase.
+    #[error("FT_DEVICE_NOT_OPENED_FOR_ERASE")]
+    DeviceNotOpenedForErase,
+    /// `FT_DEVICE_NOT_OPENED_FOR_WRITE`: the device is not opened for write.
+    #[error("FT_DEVICE_NOT_OPENED_FOR_WRITE")]
+    DeviceNotOpenedForWrite,
+    /// `FT_FAILED_TO_WRITE_DEVICE`: failed to write to the device.
+    #[error("FT_FAILED_TO_WRITE_DEVICE")]
+    FailedToWriteDevice,
+    /// `FT_EEPROM_READ_FAILED`: EEPROM read failed.
+    #[error("FT_EEPROM_READ_FAILED")]
+    EepromReadFailed,
+    /// `FT_EEPROM_WRITE_FAILED`: EEPROM write failed.
+    #[error("FT_EEPROM_WRITE_FAILED")]
+    EepromWriteFailed,
+    /// `FT_EEPROM_ERASE_FAILED`: EEPROM erase failed.
+    #[error("FT_EEPROM_ERASE_FAILED")]
+    EepromEraseFailed,
+    /// `FT_EEPROM_NOT_PRESENT`: no EEPROM is fitted to the device.
+    #[error("FT_EEPROM_NOT_PRESENT")]
+    EepromNotPresent,
+    /// `FT_EEPROM_NOT_PROGRAMMED`: the EEPROM is blank.
+    #[error("FT_EEPROM_NOT_PROGRAMMED")]
+    EepromNotProgrammed,
+    /// `FT_INVALID_ARGS`: invalid arguments were supplied.
+    #[error("FT_INVALID_ARGS")]
+    InvalidArgs,
+    /// `FT_NOT_SUPPORTED`: the requested operation is not supported.
+    #[error("FT_NOT_SUPPORTED")]
+    NotSupported,
+    /// `FT_NO_MORE_ITEMS`: no more items are available.
+    #[error("FT_NO_MORE_ITEMS")]
+    NoMoreItems,
+    /// `FT_TIMEOUT`: the operation timed out.
+    #[error("FT_TIMEOUT")]
+    Timeout,
+    /// `FT_OPERATION_ABORTED`: the operation was aborted.
+    #[error("FT_OPERATION_ABORTED")]
+    OperationAborted,
+    /// `FT_RESERVED_PIPE`: the pipe is reserved.
+    #[error("FT_RESERVED_PIPE")]
+    ReservedPipe,
+    /// `FT_INVALID_CONTROL_REQUEST_DIRECTION`: invalid control request direction.
+    #[error("FT_INVALID_CONTROL_REQUEST_DIRECTION")]
+    InvalidControlRequestDirection,
+    /// `FT_INVALID_CONTROL_REQUEST_TYPE`: invalid control request type.
+    #[error("FT_INVALID_CONTROL_REQUEST_TYPE")]
+    InvalidControlRequestType,
+    /// `FT_IO_PENDING`: an overlapped operation is still pending.
+    #[error("FT_IO_PENDING")]
+    IoPending,
+    /// `FT_IO_INCOMPLETE`: an overlapped operation did not complete.
+    #[error("FT_IO_INCOMPLETE")]
+    IoIncomplete,
+    /// `FT_HANDLE_EOF`: end of file on the handle.
+    #[error("FT_HANDLE_EOF")]
+    HandleEof,
+    /// `FT_BUSY`: the device or pipe is busy.
+    #[error("FT_BUSY")]
+    Busy,
+    /// `FT_NO_SYSTEM_RESOURCES`: no system resources are available.
+    #[error("FT_NO_SYSTEM_RESOURCES")]
+    NoSystemResources,
+    /// `FT_DEVICE_LIST_NOT_READY`: the device list is not ready.
+    #[error("FT_DEVICE_LIST_NOT_READY")]
+    DeviceListNotReady,
+    /// `FT_DEVICE_NOT_CONNECTED`: the device is not connected.
+    #[error("FT_DEVICE_NOT_CONNECTED")]
+    DeviceNotConnected,
+    /// `FT_INCORRECT_DEVICE_PATH`: an incorrect device path was supplied.
+    #[error("FT_INCORRECT_DEVICE_PATH")]
+    IncorrectDevicePath,
+    /// `FT_OTHER_ERROR`: an error occurred that is not one of the above.
+    #[error("FT_OTHER_ERROR")]
+    OtherError,
+    /// An `FT_STATUS` code this version of the crate does not yet map to a
+    /// named variant.
+    #[error("unrecognized FT_STATUS code: {0:?}")]
+    Unmapped(FT_Status),
 
-impl std::error::Error for Error {}
+    /// A [`crate::functions`] call was made without first loading the D3XX
+    /// driver via [`crate::dylib::load_dylib`].
+    #[error("no D3XX driver has been loaded; call load_dylib first")]
+    LibraryNotLoaded,
+    /// Failed to `dlopen`/`LoadLibrary` the D3XX driver, or to resolve one
+    /// of its symbols.
+    #[error("failed to load the D3XX driver")]
+    LibraryLoadError(#[from] libloading::Error),
+    /// A string returned by the driver (e.g. a serial number or
+    /// description) was not valid UTF-8.
+    #[error("failed to decode a string returned by the driver")]
+    StringDecodeError(#[from] std::str::Utf8Error),
+    /// An error that does not fit any of the above, e.g. an unrecognized
+    /// chip configuration byte or a failed completion-port call.
+    #[error("{0}")]
+    Other(String),
+}
 
-impl fmt::Display for Error {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        match &self {
-            Self::APIError(s) => write!(f, "API Error: {:#?}", s),
-            Self::CriticalAPIError(s) => write!(f, "Critical API Error: {:#?}", s),
+impl From<FT_Status> for Error {
+    fn from(status: FT_Status) -> Self {
+        match status {
+            FT_INVALID_HANDLE => Error::InvalidHandle,
+            FT_DEVICE_NOT_FOUND => Error::DeviceNotFound,
+            FT_DEVICE_NOT_OPENED => Error::DeviceNotOpened,
+            FT_IO_ERROR => Error::IoError,
+            FT_INSUFFICIENT_RESOURCES => Error::InsufficientResources,
+            FT_INVALID_PARAMETER => Error::InvalidParameter,
+            FT_INVALID_BAUD_RATE => Error::InvalidBaudRate,
+            FT_DEVICE_NOT_OPENED_FOR_ERASE => Error::DeviceNotOpenedForErase,
+            FT_DEVICE_NOT_OPENED_FOR_WRITE => Error::DeviceNotOpenedForWrite,
+            FT_FAILED_TO_WRITE_DEVICE => Error::FailedToWriteDevice,
+            FT_EEPROM_READ_FAILED => Error::EepromReadFailed,
+            FT_EEPROM_WRITE_FAILED => Error::EepromWriteFailed,
+            FT_EEPROM_ERASE_FAILED => Error::EepromEraseFailed,
+            FT_EEPROM_NOT_PRESENT => Error::EepromNotPresent,
+            FT_EEPROM_NOT_PROGRAMMED => Error::EepromNotProgrammed,
+            FT_INVALID_ARGS => Error::InvalidArgs,
+            FT_NOT_SUPPORTED => Error::NotSupported,
+            FT_NO_MORE_ITEMS => Error::NoMoreItems,
+            FT_TIMEOUT => Error::Timeout,
+            FT_OPERATION_ABORTED => Error::OperationAborted,
+            FT_RESERVED_PIPE => Error::ReservedPipe,
+            FT_INVALID_CONTROL_REQUEST_DIRECTION => Error::InvalidControlRequestDirection,
+            FT_INVALID_CONTROL_REQUEST_TYPE => Error::InvalidControlRequestType,
+            FT_IO_PENDING => Error::IoPending,
+            FT_IO_INCOMPLETE => Error::IoIncomplete,
+            FT_HANDLE_EOF => Error::HandleEof,
+            FT_BUSY => Error::Busy,
+            FT_NO_SYSTEM_RESOURCES => Error::NoSystemResources,
+            FT_DEVICE_LIST_NOT_READY => Error::DeviceListNotReady,
+            FT_DEVICE_NOT_CONNECTED => Error::DeviceNotConnected,
+            FT_INCORRECT_DEVICE_PATH => Error::IncorrectDevicePath,
+            FT_OTHER_ERROR => Error::OtherError,
+            other => Error::Unmapped(other),
         }
     }
 }
 
 impl From<&str> for Error {
     fn from(value: &str) -> Self {
-        Self::CriticalAPIError(value.to_string())
+        Self::Other(value.to_string())
     }
 }
 
 /// Generic crate Result object
 pub type Result<T> = std::result::Result<T, Error>;
 
+/// The error type returned by [`Device::read_all`]/[`Device::write_all`].
+///
+/// Distinguishes a hard driver failure from a partial transfer, so callers
+/// can tell "the USB link broke" from "the configured pipe timeout elapsed
+/// before every byte moved".
+#[derive(Debug, thiserror::Error)]
+pub enum TimeoutError {
+    /// The underlying pipe operation failed for a reason other than a
+    /// timeout.
+    #[error(transparent)]
+    Error(#[from] Error),
+    /// [`crate::functions::set_pipe_timeout`]'s configured timeout elapsed
+    /// before the full transfer completed.
+    #[error("pipe timed out after transferring {actual} of {expected} bytes")]
+    Timeout {
+        /// How many bytes were actually transferred before the timeout.
+        actual: usize,
+        /// How many bytes the caller requested.
+        expected: usize,
+    },
+}
+
 /// D3XX version structure.
 ///
 /// A version number contains a major version number, minor version and build/SVN version.
@@ -106,6 +281,1203 @@ impl Version {
     }
 }
 
+/// The type of D3XX device found during enumeration.
+///
+/// D3XX currently only supports the FT60x series: FT600 and FT601.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DeviceType {
+    /// FT600 device.
+    FT600,
+    /// FT601 device.
+    FT601,
+    /// A device type not recognized by this version of the crate.
+    Unknown(ULONG),
+}
+
+impl From<ULONG> for DeviceType {
+    fn from(value: ULONG) -> Self {
+        match value {
+            FT_DEVICE_600 => DeviceType::FT600,
+            FT_DEVICE_601 => DeviceType::FT601,
+            other => DeviceType::Unknown(other),
+        }
+    }
+}
+
+/// A parsed entry from the D3XX device information list.
+///
+/// This is the safe, decoded counterpart to the raw
+/// [`FT_DEVICE_LIST_INFO_NODE`] returned by
+/// [`get_device_info_list`][crate::functions::get_device_info_list]; see
+/// [`list_devices`][crate::functions::list_devices] for how to obtain one.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct DeviceInfo {
+    /// Indicates device state, e.g. whether the device is open or connected
+    /// at high speed. See the D3XX programmer's guide for the bit layout.
+    pub flags: ULONG,
+    /// Whether the device is currently open (by this or another process),
+    /// decoded from bit 0 of [`DeviceInfo::flags`].
+    pub opened: bool,
+    /// Device type, FT600 or FT601.
+    pub device_type: DeviceType,
+    /// Vendor ID and product ID packed as `(VID << 16) | PID`, as returned
+    /// by the driver.
+    pub id: ULONG,
+    /// Location ID of the device.
+    pub loc_id: ULONG,
+    /// Device serial number.
+    pub serial_number: String,
+    /// Device product description.
+    pub description: String,
+}
+
+/// Bit 0 of [`DeviceInfo::flags`]/`FT_DEVICE_LIST_INFO_NODE::Flags`: the
+/// device is currently open.
+const FT_FLAGS_OPENED: ULONG = 1;
+
+impl DeviceInfo {
+    /// USB vendor ID, decoded from [`DeviceInfo::id`].
+    pub fn vendor_id(&self) -> u16 {
+        (self.id >> 16) as u16
+    }
+
+    /// USB product ID, decoded from [`DeviceInfo::id`].
+    pub fn product_id(&self) -> u16 {
+        self.id as u16
+    }
+
+    /// Decodes one of the fixed-size, nominally nul-terminated C char
+    /// arrays used for `SerialNumber`/`Description`.
+    ///
+    /// Uses [`CStr::from_bytes_until_nul`] rather than trusting the buffer
+    /// to be nul-terminated, so a malformed or stale entry (e.g. the
+    /// driver racing a device being unplugged) decodes to a lossy best
+    /// effort instead of reading past the array.
+    fn decode_c_str(bytes: &[u8]) -> String {
+        match CStr::from_bytes_until_nul(bytes) {
+            Ok(s) => String::from_utf8_lossy(s.to_bytes()).to_string(),
+            Err(_) => String::from_utf8_lossy(bytes).to_string(),
+        }
+    }
+}
+
+impl From<FT_DEVICE_LIST_INFO_NODE> for DeviceInfo {
+    fn from(node: FT_DEVICE_LIST_INFO_NODE) -> Self {
+        // SAFETY: reinterpreting a fixed-size `[c_char; N]` array as `[u8; N]`
+        // of the same length; no pointer arithmetic or lifetime extension.
+        let serial_number = unsafe {
+            std::slice::from_raw_parts(node.SerialNumber.as_ptr() as *const u8, node.SerialNumber.len())
+        };
+        let description = unsafe {
+            std::slice::from_raw_parts(node.Description.as_ptr() as *const u8, node.Description.len())
+        };
+        DeviceInfo {
+            flags: node.Flags,
+            opened: node.Flags & FT_FLAGS_OPENED != 0,
+            device_type: DeviceType::from(node.Type),
+            id: node.ID,
+            loc_id: node.LocId,
+            serial_number: DeviceInfo::decode_c_str(serial_number),
+            description: DeviceInfo::decode_c_str(description),
+        }
+    }
+}
+
+/// A safe, RAII handle to an open FT60x device.
+///
+/// The free functions in [`crate::functions`] hand back a raw [`FT_HANDLE`]
+/// that the caller must remember to pass to [`close`][crate::functions::close]
+/// exactly once. `Device` owns that handle instead, opening it with one of
+/// the `open_by_*` constructors and closing it automatically when the value
+/// is dropped, so a handle can neither leak nor be closed twice.
+///
+/// # Example
+///
+/// ```no_run
+/// use libftd3xx::types::Device;
+///
+/// let device = Device::open_by_index(0).unwrap();
+/// // `device` is closed automatically here.
+/// ```
+#[derive(Debug)]
+pub struct Device {
+    handle: FT_HANDLE,
+    // The closure registered with `set_notification_callback`, if any. Boxed
+    // twice so the pointer handed to the driver as `pvArgs` is thin; freed
+    // only after `FT_ClearNotificationCallback` has returned, since the
+    // driver may invoke it from its own thread until then.
+    notification: std::cell::Cell<Option<*mut NotificationClosure>>,
+}
+
+// SAFETY: an FT_HANDLE is an opaque pointer owned exclusively by this
+// `Device`; the D3XX driver supports calls on it from any single thread.
+unsafe impl Send for Device {}
+
+/// Alias for [`Device`], mirroring the name of the sibling `libftd2xx`
+/// crate's RAII handle type (`FtdiCommon`/`Ftdi`) for users porting code
+/// between the two.
+pub type Ftdi = Device;
+
+impl Device {
+    /// Open a device by its index in the list of connected D3XX devices.
+    ///
+    /// See [`create_by_index`][crate::functions::create_by_index].
+    pub fn open_by_index(index: ULONG) -> Result<Device> {
+        Ok(Device {
+            handle: crate::functions::create_by_index(index)?,
+            notification: std::cell::Cell::new(None),
+        })
+    }
+
+    /// Open a device by its serial number.
+    ///
+    /// See [`create_by_serial_number`][crate::functions::create_by_serial_number].
+    pub fn open_by_serial_number<S: Into<String>>(serial: S) -> Result<Device> {
+        Ok(Device {
+            handle: crate::functions::create_by_serial_number(serial)?,
+            notification: std::cell::Cell::new(None),
+        })
+    }
+
+    /// Open a device by its product description.
+    ///
+    /// See [`create_by_description`][crate::functions::create_by_description].
+    pub fn open_by_description<S: Into<String>>(description: S) -> Result<Device> {
+        Ok(Device {
+            handle: crate::functions::create_by_description(description)?,
+            notification: std::cell::Cell::new(None),
+        })
+    }
+
+    /// Returns the raw handle backing this device.
+    ///
+    /// This is an escape hatch for calling [`crate::functions`] that do not
+    /// yet have a method on `Device`.
+    pub fn handle(&self) -> FT_HANDLE {
+        self.handle
+    }
+
+    /// Write data to a pipe.
+    ///
+    /// See [`write_pipe`][crate::functions::write_pipe].
+    pub fn write_pipe(&self, pipe_id: u8, buffer: &mut Vec<u8>) -> Result<ULONG> {
+        crate::functions::write_pipe(self.handle, pipe_id, buffer, std::ptr::null_mut())
+    }
+
+    /// Read data from a pipe.
+    ///
+    /// See [`read_pipe`][crate::functions::read_pipe].
+    pub fn read_pipe(&self, pipe_id: u8, buffer: &mut Vec<u8>) -> Result<ULONG> {
+        crate::functions::read_pipe(self.handle, pipe_id, buffer, std::ptr::null_mut())
+    }
+
+    /// Aborts all pending transfers for a pipe.
+    ///
+    /// See [`abort_pipe`][crate::functions::abort_pipe].
+    pub fn abort_pipe(&self, pipe_id: u8) -> Result<()> {
+        crate::functions::abort_pipe(self.handle, pipe_id)
+    }
+
+    /// Sets the timeout for reads/writes on a pipe.
+    ///
+    /// See [`set_pipe_timeout`][crate::functions::set_pipe_timeout].
+    pub fn set_pipe_timeout(&self, pipe_id: u8, timeout_ms: ULONG) -> Result<()> {
+        crate::functions::set_pipe_timeout(self.handle, pipe_id, timeout_ms)
+    }
+
+    /// Reads exactly `buffer.len()` bytes from `pipe_id`, looping
+    /// [`Device::read_pipe`] until the buffer is full or the pipe's
+    /// configured timeout (see [`Device::set_pipe_timeout`]) elapses.
+    pub fn read_all(&self, pipe_id: u8, buffer: &mut [u8]) -> std::result::Result<(), TimeoutError> {
+        let mut filled = 0;
+        while filled < buffer.len() {
+            let mut chunk = vec![0u8; buffer.len() - filled];
+            let (n, result) =
+                crate::functions::read_pipe_partial(self.handle, pipe_id, &mut chunk, std::ptr::null_mut());
+            let n = n as usize;
+            buffer[filled..filled + n].copy_from_slice(&chunk[..n]);
+            filled += n;
+            match result {
+                Ok(()) if n == 0 => {
+                    // No error, but no progress either: looping again would
+                    // spin forever.
+                    return Err(TimeoutError::Timeout {
+                        actual: filled,
+                        expected: buffer.len(),
+                    });
+                }
+                Ok(()) => {}
+                Err(Error::Timeout) => {
+                    return Err(TimeoutError::Timeout {
+                        actual: filled,
+                        expected: buffer.len(),
+                    })
+                }
+                Err(e) => return Err(TimeoutError::Error(e)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes all of `data` to `pipe_id`, looping [`Device::write_pipe`]
+    /// until every byte is sent or the pipe's configured timeout (see
+    /// [`Device::set_pipe_timeout`]) elapses.
+    pub fn write_all(&self, pipe_id: u8, data: &[u8]) -> std::result::Result<(), TimeoutError> {
+        let mut sent = 0;
+        while sent < data.len() {
+            let mut chunk = data[sent..].to_vec();
+            let (n, result) =
+                crate::functions::write_pipe_partial(self.handle, pipe_id, &mut chunk, std::ptr::null_mut());
+            sent += n as usize;
+            match result {
+                Ok(()) if n == 0 => {
+                    // No error, but no progress either: looping again would
+                    // spin forever.
+                    return Err(TimeoutError::Timeout {
+                        actual: sent,
+                        expected: data.len(),
+                    });
+                }
+                Ok(()) => {}
+                Err(Error::Timeout) => {
+                    return Err(TimeoutError::Timeout {
+                        actual: sent,
+                        expected: data.len(),
+                    })
+                }
+                Err(e) => return Err(TimeoutError::Error(e)),
+            }
+        }
+        Ok(())
+    }
+
+    /// Resets the device port.
+    ///
+    /// See [`reset_device_port`][crate::functions::reset_device_port].
+    pub fn reset_device_port(&self) -> Result<()> {
+        crate::functions::reset_device_port(self.handle)
+    }
+
+    /// Cycles (resets) the device port, causing the device to be
+    /// re-enumerated by the host.
+    ///
+    /// See [`cycle_device_port`][crate::functions::cycle_device_port].
+    pub fn cycle_device_port(&self) -> Result<()> {
+        crate::functions::cycle_device_port(self.handle)
+    }
+
+    /// Opens a device by its index, equivalent to
+    /// [`Device::open_by_index`]. Named to match the constructor style of
+    /// the sibling `libftd2xx` crate's `Ftdi` type.
+    pub fn with_index(index: ULONG) -> Result<Device> {
+        Self::open_by_index(index)
+    }
+
+    /// Opens a device by its serial number, equivalent to
+    /// [`Device::open_by_serial_number`]. Named to match the constructor
+    /// style of the sibling `libftd2xx` crate's `Ftdi` type.
+    pub fn with_serial_number<S: Into<String>>(serial: S) -> Result<Device> {
+        Self::open_by_serial_number(serial)
+    }
+
+    /// Returns a [`Pipe`] view over one of this device's endpoints,
+    /// implementing [`std::io::Read`]/[`std::io::Write`].
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use libftd3xx::types::Device;
+    /// use std::io::{Read, Write};
+    ///
+    /// let device = Device::open_by_index(0).unwrap();
+    /// device.pipe(0x02).write_all(b"hello").unwrap();
+    ///
+    /// let mut buf = [0u8; 64];
+    /// device.pipe(0x82).read(&mut buf).unwrap();
+    /// ```
+    pub fn pipe(&self, pipe_id: u8) -> Pipe<'_> {
+        Pipe {
+            device: self,
+            pipe_id,
+        }
+    }
+
+    /// Returns the chip configuration.
+    ///
+    /// See [`get_chip_configuration`][crate::functions::get_chip_configuration].
+    pub fn get_chip_configuration(&self) -> Result<FT_60XCONFIGURATION> {
+        crate::functions::get_chip_configuration(self.handle)
+    }
+
+    /// Sets the chip configuration, or resets it to defaults if `config` is `None`.
+    ///
+    /// See [`set_chip_configuration`][crate::functions::set_chip_configuration].
+    pub fn set_chip_configuration(&self, config: Option<FT_60XCONFIGURATION>) -> Result<()> {
+        crate::functions::set_chip_configuration(self.handle, config)
+    }
+
+    /// Returns the chip configuration, decoded into a [`ChipConfig`].
+    ///
+    /// This is a typed alternative to [`Device::get_chip_configuration`] for
+    /// the fields this crate understands; requires the `config` feature.
+    #[cfg(feature = "config")]
+    pub fn chip_config(&self) -> Result<ChipConfig> {
+        ChipConfig::try_from(self.get_chip_configuration()?)
+    }
+
+    /// Sets the chip configuration from a [`ChipConfig`].
+    ///
+    /// This is a typed alternative to [`Device::set_chip_configuration`];
+    /// requires the `config` feature.
+    #[cfg(feature = "config")]
+    pub fn set_chip_config(&self, config: &ChipConfig) -> Result<()> {
+        config.validate()?;
+        let mut raw = self.get_chip_configuration()?;
+        config.apply_to(&mut raw);
+        self.set_chip_configuration(Some(raw))
+    }
+
+    /// Reads the current chip configuration, lets `edit` mutate its typed
+    /// fields, validates the result, and writes it back.
+    ///
+    /// # Example
+    ///
+    /// ```no_run
+    /// use libftd3xx::types::{Device, FifoMode};
+    ///
+    /// let device = Device::open_by_index(0).unwrap();
+    /// device.update_chip_config(|config| config.fifo_mode = FifoMode::Fifo600).unwrap();
+    /// ```
+    #[cfg(feature = "config")]
+    pub fn update_chip_config<F: FnOnce(&mut ChipConfig)>(&self, edit: F) -> Result<()> {
+        let mut config = self.chip_config()?;
+        edit(&mut config);
+        self.set_chip_config(&config)
+    }
+
+    /// Registers `callback` to be invoked by the driver whenever data
+    /// becomes available on a pipe, or a GPIO line changes.
+    ///
+    /// Replaces any previously registered callback. The closure is boxed
+    /// and its address passed to the driver as the `pvArgs` context; it
+    /// stays alive until [`Device::clear_notification_callback`] is called
+    /// or this `Device` is dropped, both of which call
+    /// `FT_ClearNotificationCallback` before freeing it, so the driver can
+    /// never invoke a freed closure.
+    ///
+    /// See [`set_notification_callback`][crate::functions::set_notification_callback].
+    pub fn set_notification_callback<F>(&self, callback: F) -> Result<()>
+    where
+        F: FnMut(NotificationData) + Send + 'static,
+    {
+        self.clear_notification_callback()?;
+        let boxed: Box<NotificationClosure> = Box::new(Box::new(callback));
+        let context = Box::into_raw(boxed);
+        let result = crate::functions::set_notification_callback(
+            self.handle,
+            notification_trampoline,
+            context as *mut std::ffi::c_void,
+        );
+        match result {
+            Ok(()) => {
+                self.notification.set(Some(context));
+                Ok(())
+            }
+            Err(e) => {
+                // SAFETY: the driver never saw `context`, since
+                // `set_notification_callback` failed.
+                let _ = unsafe { Box::from_raw(context) };
+                Err(e)
+            }
+        }
+    }
+
+    /// Unregisters the callback set by [`Device::set_notification_callback`],
+    /// if any. A no-op if none is currently registered.
+    ///
+    /// See [`clear_notification_callback`][crate::functions::clear_notification_callback].
+    pub fn clear_notification_callback(&self) -> Result<()> {
+        let Some(context) = self.notification.take() else {
+            return Ok(());
+        };
+        crate::functions::clear_notification_callback(self.handle)?;
+        // SAFETY: `FT_ClearNotificationCallback` returned, so the driver
+        // will not invoke this closure again; safe to free it now.
+        let _ = unsafe { Box::from_raw(context) };
+        Ok(())
+    }
+
+    /// Enables GPIO mode on `outputs` and `inputs`, configuring each line's
+    /// direction accordingly.
+    ///
+    /// See [`crate::functions::enable_gpio`].
+    pub fn enable_gpio(&self, outputs: &[GpioPin], inputs: &[GpioPin]) -> Result<()> {
+        let mask = outputs.iter().chain(inputs).fold(0, |m, pin| m | pin.mask());
+        let direction = outputs.iter().fold(0, |m, pin| m | pin.mask());
+        crate::functions::enable_gpio(self.handle, mask, direction)
+    }
+
+    /// Writes a level to each GPIO line in `pins`, leaving every other line
+    /// unchanged.
+    ///
+    /// See [`crate::functions::write_gpio`].
+    pub fn write_gpio(&self, pins: &[(GpioPin, bool)]) -> Result<()> {
+        let mask = pins.iter().fold(0, |m, (pin, _)| m | pin.mask());
+        let data = pins
+            .iter()
+            .fold(0, |m, (pin, level)| if *level { m | pin.mask() } else { m });
+        crate::functions::write_gpio(self.handle, mask, data)
+    }
+
+    /// Reads the current level of every GPIO line.
+    ///
+    /// See [`crate::functions::read_gpio`].
+    pub fn read_gpio(&self) -> Result<GpioState> {
+        crate::functions::read_gpio(self.handle).map(GpioState::from)
+    }
+
+    /// Sets the pull configuration of each GPIO line in `pins`.
+    ///
+    /// See [`crate::functions::set_gpio_pull`].
+    pub fn set_gpio_pull(&self, pins: &[GpioPin], pull: GpioPull) -> Result<()> {
+        let mask = pins.iter().fold(0, |m, pin| m | pin.mask());
+        crate::functions::set_gpio_pull(self.handle, mask, pull)
+    }
+}
+
+impl Drop for Device {
+    fn drop(&mut self) {
+        // Closing a handle is infallible from the caller's point of view:
+        // there is nothing sensible to do with an error from `Drop`.
+        let _ = self.clear_notification_callback();
+        let _ = crate::functions::close(self.handle);
+    }
+}
+
+/// The reason a [`Device`] notification callback registered with
+/// [`Device::set_notification_callback`] was invoked.
+#[derive(Debug, Clone, Copy)]
+pub enum NotificationData {
+    /// Data is available to be read on a pipe.
+    DataAvailable {
+        /// The pipe the data arrived on.
+        pipe_id: u8,
+    },
+    /// A GPIO line changed.
+    Gpio {
+        /// The raw GPIO bitmask reported by the driver.
+        state: u8,
+    },
+    /// An `E_FT_NOTIFICATION_CALLBACK_TYPE` the driver has not been seen to
+    /// send; carries the raw value instead of guessing at its meaning.
+    Unknown(ULONG),
+}
+
+type NotificationClosure = Box<dyn FnMut(NotificationData) + Send>;
+
+/// The monomorphic `extern "C"` entry point registered with the driver by
+/// every [`Device::set_notification_callback`] call.
+///
+/// Reconstructs the boxed closure from `context` and invokes it; it does
+/// *not* take ownership back (`Box::from_raw` happens only in
+/// [`Device::clear_notification_callback`]/`Drop`), since the driver may
+/// call this trampoline again before the callback is cleared.
+extern "C" fn notification_trampoline(
+    context: *mut std::ffi::c_void,
+    callback_type: ULONG,
+    data: *mut std::ffi::c_void,
+) {
+    // SAFETY: `context` was produced by `Box::into_raw` in
+    // `Device::set_notification_callback` and is not freed until
+    // `FT_ClearNotificationCallback` has returned, which this trampoline
+    // can only be called before.
+    let closure = unsafe { &mut *(context as *mut NotificationClosure) };
+    let notification = match callback_type {
+        // E_FT_NOTIFICATION_CALLBACK_TYPE_DATA: `data` points to a
+        // `FT_NOTIFICATION_CALLBACK_INFO_DATA`, not a raw endpoint byte.
+        0 => NotificationData::DataAvailable {
+            // SAFETY: the driver passes a valid
+            // `FT_NOTIFICATION_CALLBACK_INFO_DATA*` for this callback type.
+            pipe_id: unsafe { (*(data as *const FT_NOTIFICATION_CALLBACK_INFO_DATA)).ucEndpointNo },
+        },
+        // E_FT_NOTIFICATION_CALLBACK_TYPE_GPIO: `data` points to a
+        // `FT_NOTIFICATION_CALLBACK_INFO_GPIO`.
+        1 => NotificationData::Gpio {
+            // SAFETY: the driver passes a valid
+            // `FT_NOTIFICATION_CALLBACK_INFO_GPIO*` for this callback type.
+            state: unsafe { (*(data as *const FT_NOTIFICATION_CALLBACK_INFO_GPIO)).ucGpio },
+        },
+        other => NotificationData::Unknown(other),
+    };
+    closure(notification);
+}
+
+/// The FIFO interface mode of an FT60x chip configuration.
+///
+/// Requires the `config` feature.
+#[cfg(feature = "config")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FifoMode {
+    /// 245 FIFO mode: a single synchronous/asynchronous FIFO interface,
+    /// compatible with the FT600/601's predecessors.
+    Fifo245,
+    /// 600 FIFO mode: FTDI's multi-channel FIFO interface.
+    Fifo600,
+}
+
+#[cfg(feature = "config")]
+impl TryFrom<UCHAR> for FifoMode {
+    type Error = Error;
+
+    fn try_from(value: UCHAR) -> Result<Self> {
+        match value {
+            0 => Ok(FifoMode::Fifo245),
+            1 => Ok(FifoMode::Fifo600),
+            _ => Err(Error::from(format!("unrecognized FIFOMode byte: {value}").as_str())),
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<FifoMode> for UCHAR {
+    fn from(value: FifoMode) -> Self {
+        match value {
+            FifoMode::Fifo245 => 0,
+            FifoMode::Fifo600 => 1,
+        }
+    }
+}
+
+/// The number of FIFO channels, and which directions they support, exposed
+/// by an FT60x chip configuration.
+///
+/// Requires the `config` feature.
+#[cfg(feature = "config")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ChannelConfig {
+    /// 4 bidirectional channels.
+    Four,
+    /// 2 bidirectional channels.
+    Two,
+    /// 1 bidirectional channel.
+    One,
+    /// 1 channel, OUT (host-to-device) only.
+    OneOutPipe,
+    /// 1 channel, IN (device-to-host) only.
+    OneInPipe,
+}
+
+#[cfg(feature = "config")]
+impl TryFrom<UCHAR> for ChannelConfig {
+    type Error = Error;
+
+    fn try_from(value: UCHAR) -> Result<Self> {
+        match value {
+            0 => Ok(ChannelConfig::Four),
+            1 => Ok(ChannelConfig::Two),
+            2 => Ok(ChannelConfig::One),
+            3 => Ok(ChannelConfig::OneOutPipe),
+            4 => Ok(ChannelConfig::OneInPipe),
+            _ => Err(Error::from(
+                format!("unrecognized ChannelConfig byte: {value}").as_str(),
+            )),
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<ChannelConfig> for UCHAR {
+    fn from(value: ChannelConfig) -> Self {
+        match value {
+            ChannelConfig::Four => 0,
+            ChannelConfig::Two => 1,
+            ChannelConfig::One => 2,
+            ChannelConfig::OneOutPipe => 3,
+            ChannelConfig::OneInPipe => 4,
+        }
+    }
+}
+
+/// Bit 7 of the USB `bmAttributes` power attributes byte: reserved by the
+/// USB spec and must always be set.
+#[cfg(feature = "config")]
+const POWER_ATTRIBUTES_RESERVED: UCHAR = 0x80;
+/// Bit 6: the device is self-powered rather than bus-powered.
+#[cfg(feature = "config")]
+const POWER_ATTRIBUTES_SELF_POWERED: UCHAR = 0x40;
+/// Bit 5: the device supports remote wakeup.
+#[cfg(feature = "config")]
+const POWER_ATTRIBUTES_REMOTE_WAKEUP: UCHAR = 0x20;
+
+/// The decoded USB `bmAttributes` power attributes byte of a chip
+/// configuration.
+///
+/// Requires the `config` feature.
+#[cfg(feature = "config")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PowerAttributes {
+    /// The device is self-powered rather than bus-powered.
+    pub self_powered: bool,
+    /// The device supports remote wakeup.
+    pub remote_wakeup: bool,
+}
+
+#[cfg(feature = "config")]
+impl From<UCHAR> for PowerAttributes {
+    fn from(value: UCHAR) -> Self {
+        PowerAttributes {
+            self_powered: value & POWER_ATTRIBUTES_SELF_POWERED != 0,
+            remote_wakeup: value & POWER_ATTRIBUTES_REMOTE_WAKEUP != 0,
+        }
+    }
+}
+
+#[cfg(feature = "config")]
+impl From<PowerAttributes> for UCHAR {
+    fn from(value: PowerAttributes) -> Self {
+        let mut byte = POWER_ATTRIBUTES_RESERVED;
+        if value.self_powered {
+            byte |= POWER_ATTRIBUTES_SELF_POWERED;
+        }
+        if value.remote_wakeup {
+            byte |= POWER_ATTRIBUTES_REMOTE_WAKEUP;
+        }
+        byte
+    }
+}
+
+/// The maximum power a USB3.0 bus-powered device may declare, in mA
+/// (USB 3.2 spec, table 11-21).
+#[cfg(feature = "config")]
+const MAX_POWER_CONSUMPTION_MA: u16 = 900;
+
+/// A typed view of the fields of [`FT_60XCONFIGURATION`] that control an
+/// FT600/601's FIFO behavior and USB power attributes, decoded with
+/// [`ChipConfig::try_from`] from [`Device::get_chip_configuration`] and
+/// written back with [`ChipConfig::apply_to`]/[`Device::set_chip_config`].
+///
+/// Fields not covered here (string descriptors, ...) are left untouched by
+/// [`ChipConfig::apply_to`]; read and modify
+/// [`Device::get_chip_configuration`]'s raw [`FT_60XCONFIGURATION`] directly
+/// if you need those.
+///
+/// Requires the `config` feature.
+#[cfg(feature = "config")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ChipConfig {
+    /// The FIFO clock rate, in units defined by the D3XX programming guide
+    /// (0 = 100 MHz, 1 = 66 MHz).
+    pub fifo_clock: UCHAR,
+    /// 245 FIFO vs. multi-channel 600 mode.
+    pub fifo_mode: FifoMode,
+    /// How many FIFO channels are exposed, and in which directions.
+    pub channel_config: ChannelConfig,
+    /// Self-powered/remote-wakeup USB power attributes.
+    pub power_attributes: PowerAttributes,
+    /// Declared maximum power consumption, in mA.
+    pub power_consumption_ma: u16,
+}
+
+#[cfg(feature = "config")]
+impl ChipConfig {
+    /// Starts a [`ChipConfigBuilder`] pre-populated with this
+    /// configuration's fields.
+    pub fn builder(self) -> ChipConfigBuilder {
+        ChipConfigBuilder::from_config(self)
+    }
+
+    /// Rejects field combinations the FT60x hardware does not support.
+    ///
+    /// 245 FIFO mode is a single-channel interface, so pairing it with
+    /// anything other than [`ChannelConfig::One`] would otherwise only be
+    /// caught once it bricked the device's behavior at the next open.
+    /// Likewise, a declared power consumption above what USB3.0 bus power
+    /// can supply would only surface as a host-side enumeration failure.
+    pub fn validate(&self) -> Result<()> {
+        if self.fifo_mode == FifoMode::Fifo245 && self.channel_config != ChannelConfig::One {
+            return Err(Error::from(
+                "245 FIFO mode only supports a single channel (ChannelConfig::One)",
+            ));
+        }
+        if self.power_consumption_ma > MAX_POWER_CONSUMPTION_MA {
+            return Err(Error::from(
+                format!(
+                    "power_consumption_ma {} exceeds the USB3.0 bus-power limit of {MAX_POWER_CONSUMPTION_MA}mA",
+                    self.power_consumption_ma
+                )
+                .as_str(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Writes this configuration's fields into a raw [`FT_60XCONFIGURATION`],
+    /// leaving every other field untouched.
+    pub fn apply_to(&self, raw: &mut FT_60XCONFIGURATION) {
+        raw.FIFOClock = self.fifo_clock;
+        raw.FIFOMode = UCHAR::from(self.fifo_mode);
+        raw.ChannelConfig = UCHAR::from(self.channel_config);
+        raw.PowerAttributes = UCHAR::from(self.power_attributes);
+        raw.PowerConsumption = self.power_consumption_ma;
+    }
+}
+
+#[cfg(feature = "config")]
+impl TryFrom<FT_60XCONFIGURATION> for ChipConfig {
+    type Error = Error;
+
+    fn try_from(raw: FT_60XCONFIGURATION) -> Result<Self> {
+        Ok(ChipConfig {
+            fifo_clock: raw.FIFOClock,
+            fifo_mode: FifoMode::try_from(raw.FIFOMode)?,
+            channel_config: ChannelConfig::try_from(raw.ChannelConfig)?,
+            power_attributes: PowerAttributes::from(raw.PowerAttributes),
+            power_consumption_ma: raw.PowerConsumption,
+        })
+    }
+}
+
+/// Builder for [`ChipConfig`] that validates field combinations (see
+/// [`ChipConfig::validate`]) before they can be written to a device.
+///
+/// Requires the `config` feature.
+#[cfg(feature = "config")]
+#[derive(Debug, Clone)]
+pub struct ChipConfigBuilder {
+    fifo_clock: UCHAR,
+    fifo_mode: FifoMode,
+    channel_config: ChannelConfig,
+    power_attributes: PowerAttributes,
+    power_consumption_ma: u16,
+}
+
+#[cfg(feature = "config")]
+impl ChipConfigBuilder {
+    /// Starts a builder pre-populated with `config`'s fields.
+    pub fn from_config(config: ChipConfig) -> Self {
+        ChipConfigBuilder {
+            fifo_clock: config.fifo_clock,
+            fifo_mode: config.fifo_mode,
+            channel_config: config.channel_config,
+            power_attributes: config.power_attributes,
+            power_consumption_ma: config.power_consumption_ma,
+        }
+    }
+
+    /// Sets the FIFO clock rate.
+    pub fn fifo_clock(mut self, fifo_clock: UCHAR) -> Self {
+        self.fifo_clock = fifo_clock;
+        self
+    }
+
+    /// Sets the FIFO mode.
+    pub fn fifo_mode(mut self, fifo_mode: FifoMode) -> Self {
+        self.fifo_mode = fifo_mode;
+        self
+    }
+
+    /// Sets the channel configuration.
+    pub fn channel_config(mut self, channel_config: ChannelConfig) -> Self {
+        self.channel_config = channel_config;
+        self
+    }
+
+    /// Sets the USB power attributes.
+    pub fn power_attributes(mut self, power_attributes: PowerAttributes) -> Self {
+        self.power_attributes = power_attributes;
+        self
+    }
+
+    /// Sets the declared maximum power consumption, in mA.
+    pub fn power_consumption_ma(mut self, power_consumption_ma: u16) -> Self {
+        self.power_consumption_ma = power_consumption_ma;
+        self
+    }
+
+    /// Validates the configured fields and builds a [`ChipConfig`].
+    pub fn build(self) -> Result<ChipConfig> {
+        let config = ChipConfig {
+            fifo_clock: self.fifo_clock,
+            fifo_mode: self.fifo_mode,
+            channel_config: self.channel_config,
+            power_attributes: self.power_attributes,
+            power_consumption_ma: self.power_consumption_ma,
+        };
+        config.validate()?;
+        Ok(config)
+    }
+}
+
+impl From<Error> for std::io::Error {
+    fn from(err: Error) -> Self {
+        match err {
+            Error::Timeout => std::io::Error::new(std::io::ErrorKind::TimedOut, err),
+            Error::IoPending | Error::Busy => {
+                std::io::Error::new(std::io::ErrorKind::WouldBlock, err)
+            }
+            _ => std::io::Error::new(std::io::ErrorKind::Other, err),
+        }
+    }
+}
+
+/// A view over one endpoint of a [`Device`], implementing [`std::io::Read`]
+/// and [`std::io::Write`].
+///
+/// The FT600/601 "USB3.0-to-FIFO bridge" exposes each pipe as an independent
+/// byte stream endpoint; wrapping one in the standard I/O traits lets it
+/// compose with the rest of the Rust ecosystem (buffered readers/writers,
+/// `std::io::copy`, etc). Obtain one with [`Device::pipe`].
+pub struct Pipe<'a> {
+    device: &'a Device,
+    pipe_id: u8,
+}
+
+impl<'a> Pipe<'a> {
+    /// The pipe ID this view reads from or writes to.
+    pub fn pipe_id(&self) -> u8 {
+        self.pipe_id
+    }
+}
+
+impl<'a> std::io::Read for Pipe<'a> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let mut scratch = vec![0u8; buf.len()];
+        let bytes_transferred = self.device.read_pipe(self.pipe_id, &mut scratch)?;
+        let bytes_transferred = bytes_transferred as usize;
+        buf[..bytes_transferred].copy_from_slice(&scratch[..bytes_transferred]);
+        Ok(bytes_transferred)
+    }
+}
+
+impl<'a> std::io::Write for Pipe<'a> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let mut scratch = buf.to_vec();
+        let bytes_transferred = self.device.write_pipe(self.pipe_id, &mut scratch)?;
+        Ok(bytes_transferred as usize)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        Ok(())
+    }
+}
+
+/// An in-flight asynchronous (overlapped) transfer on a [`Device`].
+///
+/// Sustaining USB 3.0 bandwidth on FT60x requires keeping multiple transfers
+/// in flight rather than issuing blocking reads/writes one at a time. This
+/// wraps the raw `LPOVERLAPPED` dance (`FT_InitializeOverlapped` /
+/// `FT_GetOverlappedResult` / `FT_ReleaseOverlapped`) in an RAII type: the
+/// overlapped resource is always released when it is dropped.
+pub struct Overlapped<'a> {
+    device: &'a Device,
+    // Boxed so the address handed to `FT_InitializeOverlapped` stays fixed
+    // even if this `Overlapped` itself is moved.
+    raw: Box<OVERLAPPED>,
+}
+
+impl<'a> Overlapped<'a> {
+    /// Initialize a new overlapped resource for `device`.
+    ///
+    /// See [`initialize_overlapped`][crate::functions::initialize_overlapped].
+    pub fn new(device: &'a Device) -> Result<Self> {
+        let mut raw = Box::new(OVERLAPPED::default());
+        crate::functions::initialize_overlapped(device.handle, raw.as_mut() as *mut OVERLAPPED)?;
+        Ok(Overlapped { device, raw })
+    }
+
+    /// A pointer suitable for passing as the `lpOverlapped` argument of
+    /// [`crate::functions::write_pipe_ex`]/[`crate::functions::read_pipe_ex`].
+    pub fn as_mut_ptr(&mut self) -> LPOVERLAPPED {
+        self.raw.as_mut() as *mut OVERLAPPED
+    }
+
+    /// Blocks until the transfer submitted with this overlapped resource
+    /// completes, returning the number of bytes transferred.
+    ///
+    /// See [`get_overlapped_result`][crate::functions::get_overlapped_result].
+    pub fn wait(&mut self) -> Result<ULONG> {
+        let handle = self.device.handle;
+        crate::functions::get_overlapped_result(handle, self.as_mut_ptr(), true)
+    }
+
+    /// Checks whether the transfer submitted with this overlapped resource
+    /// has completed, without blocking. Returns `Ok(None)` (rather than
+    /// `Err(Error::IoIncomplete)`) while the transfer is still pending.
+    ///
+    /// See [`get_overlapped_result`][crate::functions::get_overlapped_result].
+    pub fn poll(&mut self) -> Result<Option<ULONG>> {
+        let handle = self.device.handle;
+        match crate::functions::get_overlapped_result(handle, self.as_mut_ptr(), false) {
+            Ok(bytes_transferred) => Ok(Some(bytes_transferred)),
+            Err(Error::IoIncomplete) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+impl<'a> Drop for Overlapped<'a> {
+    fn drop(&mut self) {
+        let ptr = self.raw.as_mut() as *mut OVERLAPPED;
+        let _ = crate::functions::release_overlapped(self.device.handle, ptr);
+    }
+}
+
+/// A ring of outstanding overlapped reads kept queued on a single pipe, so
+/// there is always another transfer in flight on the USB bus by the time
+/// the caller finishes processing the previous one.
+///
+/// A single synchronous [`Device::read_pipe`] call cannot saturate a USB 3.0
+/// link on its own, since the bus sits idle between the completion of one
+/// transfer and the submission of the next; keeping `depth` requests queued
+/// avoids that gap.
+pub struct TransferRing<'a> {
+    device: &'a Device,
+    pipe_id: u8,
+    slots: Vec<(Overlapped<'a>, Vec<u8>)>,
+    cursor: usize,
+}
+
+impl<'a> TransferRing<'a> {
+    /// Create a ring of `depth` buffers of `buffer_size` bytes each on
+    /// `pipe_id`, and submit an initial read on every one of them.
+    pub fn new(device: &'a Device, pipe_id: u8, depth: usize, buffer_size: usize) -> Result<Self> {
+        let mut slots = Vec::with_capacity(depth);
+        for _ in 0..depth {
+            let mut overlapped = Overlapped::new(device)?;
+            let mut buffer = vec![0u8; buffer_size];
+            let p_overlapped = overlapped.as_mut_ptr();
+            match crate::functions::read_pipe_ex(device.handle, pipe_id, &mut buffer, p_overlapped) {
+                Ok(_) | Err(Error::IoPending) => {}
+                Err(e) => return Err(e),
+            }
+            slots.push((overlapped, buffer));
+        }
+        Ok(TransferRing {
+            device,
+            pipe_id,
+            slots,
+            cursor: 0,
+        })
+    }
+
+    /// Blocks until the oldest outstanding transfer completes, returns the
+    /// bytes it received, and immediately re-arms that slot with a fresh
+    /// read so the ring stays full.
+    pub fn next(&mut self) -> Result<Vec<u8>> {
+        let index = self.cursor;
+        self.cursor = (self.cursor + 1) % self.slots.len();
+
+        let (overlapped, buffer) = &mut self.slots[index];
+        let bytes_transferred = overlapped.wait()? as usize;
+        let received = buffer[..bytes_transferred].to_vec();
+
+        let p_overlapped = overlapped.as_mut_ptr();
+        match crate::functions::read_pipe_ex(self.device.handle, self.pipe_id, buffer, p_overlapped) {
+            Ok(_) | Err(Error::IoPending) => {}
+            Err(e) => return Err(e),
+        }
+        Ok(received)
+    }
+}
+
+impl<'a> Drop for TransferRing<'a> {
+    fn drop(&mut self) {
+        // Cancel every outstanding transfer before the slots (and their
+        // `Overlapped`s) are released, so the driver is not left with
+        // pending I/O against buffers that are about to be freed.
+        let _ = self.device.abort_pipe(self.pipe_id);
+    }
+}
+
+/// A ring-buffered reader over a pipe configured for the D3XX streaming
+/// protocol (AN_412), keeping `depth` reads of `stream_size` bytes
+/// permanently in flight.
+///
+/// This layers [`crate::functions::set_stream_pipe`]/
+/// [`crate::functions::clear_stream_pipe`] setup and teardown around a
+/// [`TransferRing`], since the streaming protocol is only beneficial when
+/// paired with multiple outstanding overlapped requests.
+pub struct StreamReader<'a> {
+    device: &'a Device,
+    pipe_id: u8,
+    ring: Option<TransferRing<'a>>,
+}
+
+impl<'a> StreamReader<'a> {
+    /// Enables streaming on `pipe_id` with the given fixed `stream_size`,
+    /// and submits an initial ring of `depth` outstanding reads.
+    pub fn new(device: &'a Device, pipe_id: u8, stream_size: ULONG, depth: usize) -> Result<Self> {
+        crate::functions::set_stream_pipe(device.handle, false, false, pipe_id, stream_size)?;
+        let ring = TransferRing::new(device, pipe_id, depth, stream_size as usize)?;
+        Ok(StreamReader {
+            device,
+            pipe_id,
+            ring: Some(ring),
+        })
+    }
+
+    /// Blocks until the oldest outstanding read completes, returns its
+    /// bytes, and immediately re-arms that slot so the ring stays full.
+    pub fn next(&mut self) -> Result<Vec<u8>> {
+        self.ring
+            .as_mut()
+            .expect("StreamReader::ring is only None during Drop")
+            .next()
+    }
+}
+
+impl<'a> Drop for StreamReader<'a> {
+    fn drop(&mut self) {
+        // Drop the ring first so every outstanding transfer is aborted
+        // before the stream pipe configuration is cleared.
+        self.ring.take();
+        let _ = crate::functions::clear_stream_pipe(self.device.handle, false, false, self.pipe_id);
+    }
+}
+
+/// A ring-buffered writer over a pipe configured for the D3XX streaming
+/// protocol (AN_412), keeping `depth` writes of `stream_size` bytes
+/// permanently in flight.
+///
+/// Each [`StreamWriter::write`] call waits for the oldest slot's previous
+/// write to complete, then submits `data` (zero-padded to `stream_size`) as
+/// that slot's next write, so there is always another transfer queued on
+/// the USB bus.
+pub struct StreamWriter<'a> {
+    device: &'a Device,
+    pipe_id: u8,
+    slots: Vec<Overlapped<'a>>,
+    buffers: Vec<Vec<u8>>,
+    armed: Vec<bool>,
+    cursor: usize,
+}
+
+impl<'a> StreamWriter<'a> {
+    /// Enables streaming on `pipe_id` with the given fixed `stream_size`,
+    /// and allocates a ring of `depth` write buffers.
+    pub fn new(device: &'a Device, pipe_id: u8, stream_size: ULONG, depth: usize) -> Result<Self> {
+        crate::functions::set_stream_pipe(device.handle, false, false, pipe_id, stream_size)?;
+        let mut slots = Vec::with_capacity(depth);
+        let mut buffers = Vec::with_capacity(depth);
+        for _ in 0..depth {
+            slots.push(Overlapped::new(device)?);
+            buffers.push(vec![0u8; stream_size as usize]);
+        }
+        Ok(StreamWriter {
+            device,
+            pipe_id,
+            slots,
+            buffers,
+            armed: vec![false; depth],
+            cursor: 0,
+        })
+    }
+
+    /// Writes `data` (truncated or zero-padded to the configured stream
+    /// size) through the next slot in the ring, first waiting for that
+    /// slot's previous write, if any, to complete.
+    pub fn write(&mut self, data: &[u8]) -> Result<ULONG> {
+        let index = self.cursor;
+        self.cursor = (self.cursor + 1) % self.slots.len();
+
+        if self.armed[index] {
+            self.slots[index].wait()?;
+        }
+
+        let buffer = &mut self.buffers[index];
+        let len = data.len().min(buffer.len());
+        buffer[..len].copy_from_slice(&data[..len]);
+        buffer[len..].fill(0);
+
+        let p_overlapped = self.slots[index].as_mut_ptr();
+        let bytes_written =
+            match crate::functions::write_pipe_ex(self.device.handle, self.pipe_id, buffer, p_overlapped) {
+                Ok(n) => n,
+                // Still in flight: the whole (zero-padded) buffer will be
+                // transferred by the time this slot is next waited on.
+                Err(Error::IoPending) => buffer.len() as ULONG,
+                Err(e) => return Err(e),
+            };
+        self.armed[index] = true;
+        Ok(bytes_written)
+    }
+}
+
+impl<'a> Drop for StreamWriter<'a> {
+    fn drop(&mut self) {
+        let _ = self.device.abort_pipe(self.pipe_id);
+        let _ = crate::functions::clear_stream_pipe(self.device.handle, false, false, self.pipe_id);
+    }
+}
+
+/// One of the FT60x's general-purpose I/O lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpioPin {
+    /// `GPIO0`.
+    Gpio0,
+    /// `GPIO1`.
+    Gpio1,
+}
+
+impl GpioPin {
+    fn mask(self) -> ULONG {
+        match self {
+            GpioPin::Gpio0 => 0b01,
+            GpioPin::Gpio1 => 0b10,
+        }
+    }
+}
+
+/// Pull configuration for a GPIO line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum GpioPull {
+    /// No pull resistor.
+    None,
+    /// Pull-down resistor.
+    PullDown,
+    /// Pull-up resistor.
+    PullUp,
+}
+
+impl From<GpioPull> for ULONG {
+    fn from(pull: GpioPull) -> ULONG {
+        match pull {
+            GpioPull::None => 0,
+            GpioPull::PullDown => 1,
+            GpioPull::PullUp => 2,
+        }
+    }
+}
+
+/// The decoded level of both FT60x GPIO lines, as returned by
+/// [`Device::read_gpio`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GpioState {
+    /// Level of `GPIO0`.
+    pub gpio0: bool,
+    /// Level of `GPIO1`.
+    pub gpio1: bool,
+}
+
+impl GpioState {
+    /// Returns the level of `pin`.
+    pub fn get(&self, pin: GpioPin) -> bool {
+        match pin {
+            GpioPin::Gpio0 => self.gpio0,
+            GpioPin::Gpio1 => self.gpio1,
+        }
+    }
+}
+
+impl From<ULONG> for GpioState {
+    fn from(value: ULONG) -> Self {
+        GpioState {
+            gpio0: value & GpioPin::Gpio0.mask() != 0,
+            gpio1: value & GpioPin::Gpio1.mask() != 0,
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;